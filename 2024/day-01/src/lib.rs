@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use aoc::{parse, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Both location lists, sorted
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (mut left_numbers, mut right_numbers): (Vec<i32>, Vec<i32>) = parse::lines(input)
+            .map(|line| {
+                let [left, right] = line.ints_exact::<i32, 2>()?;
+                Ok((left, right))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
+            .unzip();
+        left_numbers.sort();
+        right_numbers.sort();
+
+        Ok((left_numbers, right_numbers))
+    }
+
+    fn part1((left_numbers, right_numbers): &Self::Input) -> impl std::fmt::Display {
+        left_numbers
+            .iter()
+            .zip(right_numbers.iter())
+            .map(|(left, right)| (left - right).abs())
+            .sum::<i32>()
+    }
+
+    fn part2((left_numbers, right_numbers): &Self::Input) -> impl std::fmt::Display {
+        let right_counts = right_numbers.iter().fold(HashMap::new(), |mut acc, num| {
+            *acc.entry(num).or_insert(0) += 1;
+            acc
+        });
+
+        left_numbers
+            .iter()
+            .map(|left| left * right_counts.get(left).unwrap_or(&0))
+            .sum::<i32>()
+    }
+}
+
+aoc::examples!(Day01 {
+    example_1: "examples/example-1.txt", part1 = 11, part2 = 31;
+});
@@ -0,0 +1,61 @@
+use aoc::{parse, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input.trim_end())
+            .map(|line| line.numbers())
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> impl std::fmt::Display {
+        reports
+            .iter()
+            .filter(|report| is_valid_report(report))
+            .count()
+    }
+
+    /// A report is also valid if removing a single level makes it valid
+    fn part2(reports: &Self::Input) -> impl std::fmt::Display {
+        reports
+            .iter()
+            .filter(|report| {
+                is_valid_report(report)
+                    || (0..report.len())
+                        .map(|i| {
+                            let mut nums = report.to_vec();
+                            nums.remove(i);
+                            nums
+                        })
+                        .any(|variation| is_valid_report(&variation))
+            })
+            .count()
+    }
+}
+
+fn is_valid_report(report: &[i32]) -> bool {
+    let mut direction = None;
+    for window in report.windows(2) {
+        if !(1..=3).contains(&(window[1] - window[0]).abs()) {
+            return false;
+        }
+
+        let is_increasing = window[1] > window[0];
+        match direction {
+            None => direction = Some(is_increasing),
+            Some(dir) if dir != is_increasing => return false,
+            _ => {}
+        }
+    }
+
+    true
+}
+
+aoc::examples!(Day02 {
+    example_1: "examples/example-1.txt", part1 = 2, part2 = 4;
+});
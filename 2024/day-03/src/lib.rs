@@ -7,27 +7,57 @@ use nom::{
     sequence::{delimited, separated_pair},
     IResult, Parser,
 };
-use std::time::Instant;
 
+use aoc::{ParseError, Solution};
 use regex::Regex;
 
-const INPUT: &str = include_str!("../input1.txt");
+pub const INPUT: &str = include_str!("../input1.txt");
 
-fn main() {
-    let start = Instant::now();
-    println!("Regex: {}, time: {:?}", regex_solution(), start.elapsed());
+pub struct Day03;
 
-    let start = Instant::now();
-    println!("Parser: {}, time: {:?}", nom_solution(), start.elapsed());
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (_, instructions) = parse_instructions(input).map_err(|e| {
+            let rest = match &e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => e.input,
+                nom::Err::Incomplete(_) => "",
+            };
+            ParseError::at_offset(
+                input,
+                input.len() - rest.len(),
+                0,
+                "an instruction like `mul(2,4)`",
+            )
+        })?;
+        Ok(instructions)
+    }
+
+    /// Every multiplication, ignoring `do()` and `don't()`
+    fn part1(instructions: &Self::Input) -> impl std::fmt::Display {
+        instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Multiply(a, b) => a * b,
+                _ => 0,
+            })
+            .sum::<u32>()
+    }
+
+    fn part2(instructions: &Self::Input) -> impl std::fmt::Display {
+        nom_solution(instructions)
+    }
 }
 
-fn regex_solution() -> u32 {
+/// Part 2 without a parser, splitting the memory on `do()` and `don't()` instead
+pub fn regex_solution(input: &str) -> u32 {
     let re_mul = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
     let re_split = Regex::new(r"do\(\)|don't\(\)").unwrap();
 
     re_split
-        .split(INPUT)
-        .zip(std::iter::once(true).chain(re_split.find_iter(INPUT).map(|m| m.as_str() == "do()")))
+        .split(input)
+        .zip(std::iter::once(true).chain(re_split.find_iter(input).map(|m| m.as_str() == "do()")))
         .filter_map(|(part, enabled)| {
             if !enabled {
                 return None;
@@ -47,9 +77,7 @@ fn regex_solution() -> u32 {
         .sum()
 }
 
-fn nom_solution() -> u32 {
-    let (_, instructions) = parse_instructions(INPUT).unwrap();
-
+fn nom_solution(instructions: &[Instruction]) -> u32 {
     instructions
         .iter()
         .fold((true, 0), |(enabled, acc), instruction| match instruction {
@@ -61,7 +89,7 @@ fn nom_solution() -> u32 {
 }
 
 #[derive(Debug, Clone)]
-enum Instruction {
+pub enum Instruction {
     Multiply(u32, u32),
     Do,
     Dont,
@@ -88,3 +116,8 @@ fn mul(input: &str) -> IResult<&str, Instruction> {
 fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
     many1(many_till(anychar, instruction).map(|(_, instruction)| instruction))(input)
 }
+
+aoc::examples!(Day03 {
+    example_1: "examples/example-1.txt", part1 = 161;
+    example_2: "examples/example-2.txt", part2 = 48;
+});
@@ -0,0 +1,64 @@
+use aoc::{check::Check, Direction, Grid, ParseError, Point, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+/// Every line through the grid is covered by one of these, patterns are also checked reversed
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Right,     // →
+    Direction::Down,      // ↓
+    Direction::DownRight, // ↘
+    Direction::UpRight,   // ↗
+];
+
+fn find_pattern(grid: &Grid<char>, pattern: &[char], start: Point, direction: Direction) -> bool {
+    let chars: Option<Vec<char>> = (0..pattern.len())
+        .scan(start, |pos, _| {
+            let c = grid.get(*pos).copied();
+            *pos += direction;
+            Some(c)
+        })
+        .collect();
+
+    chars.is_some_and(|chars| chars == pattern || chars.iter().rev().eq(pattern))
+}
+
+fn find_all_patterns(grid: &Grid<char>, pattern: &[char]) -> usize {
+    grid.points()
+        .flat_map(|pos| {
+            DIRECTIONS
+                .iter()
+                .filter(move |dir| find_pattern(grid, pattern, pos, **dir))
+        })
+        .count()
+}
+
+fn find_crosses(grid: &Grid<char>, pattern: &[char]) -> usize {
+    grid.points()
+        .filter(|&pos| {
+            find_pattern(grid, pattern, pos, Direction::DownRight)
+                && find_pattern(grid, pattern, pos + (0, 2), Direction::UpRight)
+        })
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_checked(input, Check::grid().chars("XMAS"), |c| c)
+    }
+
+    fn part1(grid: &Self::Input) -> impl std::fmt::Display {
+        find_all_patterns(grid, &['X', 'M', 'A', 'S'])
+    }
+
+    fn part2(grid: &Self::Input) -> impl std::fmt::Display {
+        find_crosses(grid, &['M', 'A', 'S'])
+    }
+}
+
+aoc::examples!(Day04 {
+    example_1: "examples/example-1.txt", part1 = 18, part2 = 9;
+});
@@ -0,0 +1,103 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc::{parse, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+pub struct Manual {
+    rules: HashMap<u32, Vec<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [rules_input, updates_input] = parse::paragraphs_exact(input)?;
+
+        let mut rules: HashMap<u32, Vec<u32>> = HashMap::new();
+        for line in &rules_input {
+            let (id, after) = line.split_once("|")?;
+            rules.entry(id.parse()?).or_default().push(after.parse()?);
+        }
+        let updates = updates_input
+            .iter()
+            .map(|line| line.numbers())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manual { rules, updates })
+    }
+
+    fn part1(manual: &Self::Input) -> impl std::fmt::Display {
+        manual
+            .updates
+            .iter()
+            .filter(|update| is_valid_update(&manual.rules, update))
+            .map(|update| middle_value(update))
+            .sum::<u32>()
+    }
+
+    fn part2(manual: &Self::Input) -> impl std::fmt::Display {
+        manual
+            .updates
+            .iter()
+            .filter(|update| !is_valid_update(&manual.rules, update))
+            .map(|update| {
+                let mut update = update.clone();
+                sort_update(&manual.rules, &mut update);
+                middle_value(&update)
+            })
+            .sum::<u32>()
+    }
+}
+
+fn is_valid_update(rules: &HashMap<u32, Vec<u32>>, update: &[u32]) -> bool {
+    !update.iter().enumerate().any(|(i, current)| {
+        rules
+            .get(current)
+            .map(|afters| update.iter().take(i).any(|prev| afters.contains(prev)))
+            .unwrap_or(false)
+    })
+}
+
+fn sort_update(rules: &HashMap<u32, Vec<u32>>, update: &mut [u32]) {
+    update.sort_by(|a, b| {
+        if rules.get(a).is_some_and(|afters| afters.contains(b)) {
+            Ordering::Less
+        } else if rules.get(b).is_some_and(|afters| afters.contains(a)) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+}
+
+fn middle_value(update: &[u32]) -> u32 {
+    update[update.len() / 2]
+}
+
+aoc::examples!(Day05 {
+    example_1: "examples/example-1.txt", part1 = 143, part2 = 123;
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_pages_that_arent_numbers() {
+        let error = Day05::parse("47|53\n\n75,x,61\n").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (3, 4, "x")
+        );
+
+        let error = Day05::parse("47|five\n\n75,47,61\n").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (1, 4, "five")
+        );
+    }
+}
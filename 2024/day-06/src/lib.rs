@@ -0,0 +1,142 @@
+use aoc::{check::Check, Direction, FxHashSet as HashSet, Grid, ParseError, Solution};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Debug, Clone)]
+struct Guard {
+    position: (isize, isize),
+    direction: Direction,
+    visited: HashSet<((isize, isize), Direction)>,
+    start_position: (isize, isize),
+}
+
+#[derive(Debug, PartialEq)]
+enum GuardState {
+    OutOfBounds,
+    InLoop,
+}
+
+impl Guard {
+    fn new(start_position: (isize, isize)) -> Self {
+        let mut visited = HashSet::default();
+        visited.insert((start_position, Direction::Up));
+
+        Self {
+            position: start_position,
+            direction: Direction::Up,
+            visited,
+            start_position,
+        }
+    }
+
+    fn move_in_curr_direction(&mut self) {
+        self.position = self.position + self.direction;
+        self.visited.insert((self.position, self.direction));
+    }
+
+    fn reset(&mut self) {
+        self.position = self.start_position;
+        self.visited.clear();
+        self.visited.insert((self.start_position, Direction::Up));
+        self.direction = Direction::Up;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    grid: Grid<char>,
+    guard: Guard,
+    obstacle: (isize, isize),
+}
+
+impl Lab {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid::parse_checked(input, Check::grid().chars("#.^"), |c| c)?;
+        let start_position = grid
+            .find(|&c| c == '^')
+            .ok_or_else(|| ParseError::at_offset(input, input.len(), 0, "a guard `^`"))?;
+        grid[start_position] = '.';
+
+        Ok(Self {
+            grid,
+            guard: Guard::new(start_position.as_tuple()),
+            obstacle: (-1, -1),
+        })
+    }
+
+    fn run(&mut self) -> GuardState {
+        match self.move_guard() {
+            GuardState::InLoop => GuardState::InLoop,
+            GuardState::OutOfBounds => GuardState::OutOfBounds,
+        }
+    }
+
+    fn set_obstacle(&mut self, position: (isize, isize)) {
+        self.grid[position] = '#';
+        self.obstacle = position;
+    }
+
+    fn move_guard(&mut self) -> GuardState {
+        let mut next_pos = self.guard.position + self.guard.direction;
+        while let Some(&cell) = self.grid.get(next_pos) {
+            if cell == '#' {
+                self.guard.direction = self.guard.direction.turn_right();
+                return self.move_guard();
+            }
+
+            let state = (next_pos, self.guard.direction);
+            if self.guard.visited.contains(&state) {
+                return GuardState::InLoop;
+            }
+
+            self.guard.move_in_curr_direction();
+            next_pos = self.guard.position + self.guard.direction;
+        }
+
+        GuardState::OutOfBounds
+    }
+
+    /// The positions the guard walks through before leaving the lab
+    fn visited_positions(&self) -> HashSet<(isize, isize)> {
+        let mut lab = self.clone();
+        lab.run();
+        lab.guard
+            .visited
+            .iter()
+            .map(|((x, y), _)| (*x, *y))
+            .collect()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Lab::parse(input.trim_end())
+    }
+
+    fn part1(lab: &Self::Input) -> impl std::fmt::Display {
+        lab.visited_positions().len()
+    }
+
+    /// Only obstacles on the original route can change it
+    fn part2(lab: &Self::Input) -> impl std::fmt::Display {
+        lab.visited_positions()
+            .into_par_iter()
+            .filter(|&pos| lab.grid[pos] == '.' && pos != lab.guard.start_position)
+            .filter(|&obstacle| {
+                let mut lab_clone = lab.clone();
+                lab_clone.set_obstacle(obstacle);
+                lab_clone.guard.reset();
+                lab_clone.run() == GuardState::InLoop
+            })
+            .count()
+    }
+}
+
+aoc::examples!(Day06 {
+    example_1: "examples/example-1.txt", part1 = 41, part2 = 6;
+});
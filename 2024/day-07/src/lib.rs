@@ -1,10 +1,9 @@
-use std::time::Instant;
-
+use aoc::{parse, ParseError, Solution};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-const INPUT: &str = include_str!("../input1.txt");
+pub const INPUT: &str = include_str!("../input1.txt");
 
-struct Line {
+pub struct Line {
     sum: usize,
     numbers: Vec<usize>,
 }
@@ -43,22 +42,32 @@ fn solve(lines: &[Line], part2: bool) -> usize {
         .sum()
 }
 
-fn main() {
-    let start = Instant::now();
-    let lines: Vec<Line> = INPUT
-        .lines()
-        .map(|line| {
-            let (sum, numbers) = line.split_once(':').unwrap();
-            let sum = sum.parse().unwrap();
-            let numbers = numbers
-                .split_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect();
-            Line { sum, numbers }
-        })
-        .collect();
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let (sum, numbers) = line.split_once(":")?;
+                Ok(Line {
+                    sum: sum.parse()?,
+                    numbers: numbers.numbers()?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> impl std::fmt::Display {
+        solve(lines, false)
+    }
 
-    println!("Part 1: {}", solve(&lines, false));
-    println!("Part 2: {}", solve(&lines, true));
-    println!("Time: {:?}", start.elapsed());
+    fn part2(lines: &Self::Input) -> impl std::fmt::Display {
+        solve(lines, true)
+    }
 }
+
+aoc::examples!(Day07 {
+    example_1: "examples/example-1.txt", part1 = 3749, part2 = 11387;
+});
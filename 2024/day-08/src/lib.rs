@@ -1,13 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc::Point;
+use aoc::{check::Check, math, ParseError, Point, Solution};
 
-const INPUT: &str = include_str!("../input1.txt");
+pub const INPUT: &str = include_str!("../input1.txt");
 
 fn parse_input(input: &str) -> (HashMap<char, Vec<Point>>, isize, isize) {
     let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
     let height = input.lines().count() as isize;
-    let width = input.lines().next().unwrap().len() as isize;
+    let width = input.lines().next().map_or(0, str::len) as isize;
 
     for (y, line) in input.lines().enumerate() {
         for (x, c) in line.chars().enumerate() {
@@ -72,7 +72,7 @@ fn calculate_antinodes_part2(
                 let p2 = positions[j];
 
                 let diff = p2 - p1;
-                let gcd = gcd(diff.x, diff.y);
+                let gcd = math::gcd(diff.x, diff.y);
                 let step = (diff.x / gcd, diff.y / gcd);
 
                 let mut p = p1;
@@ -93,20 +93,28 @@ fn calculate_antinodes_part2(
     antinodes
 }
 
-fn main() {
-    let (antennas, width, height) = parse_input(INPUT);
-    println!(
-        "Part 1: {}\nPart 2: {}",
-        calculate_antinodes(&antennas, width, height).len(),
-        calculate_antinodes_part2(&antennas, width, height).len()
-    );
-}
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// Antenna positions per frequency, and the width and height of the map
+    type Input = (HashMap<char, Vec<Point>>, isize, isize);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let check = Check::grid().chars_matching("an antenna or `.`", |c| {
+            c == '.' || c.is_ascii_alphanumeric()
+        });
+        Ok(parse_input(&check.normalize(input)?))
+    }
 
-fn gcd(mut a: isize, mut b: isize) -> isize {
-    while b != 0 {
-        let temp = b;
-        b = a % b;
-        a = temp;
+    fn part1((antennas, width, height): &Self::Input) -> impl std::fmt::Display {
+        calculate_antinodes(antennas, *width, *height).len()
+    }
+
+    fn part2((antennas, width, height): &Self::Input) -> impl std::fmt::Display {
+        calculate_antinodes_part2(antennas, *width, *height).len()
     }
-    a
 }
+
+aoc::examples!(Day08 {
+    example_1: "examples/example-1.txt", part1 = 14, part2 = 34;
+});
@@ -0,0 +1,143 @@
+use aoc::{ParseError, RangeSet, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Debug, Clone)]
+struct FileBlock {
+    id: usize,
+    size: usize,
+}
+
+#[derive(Debug, Clone)]
+enum DiskEntry {
+    File(FileBlock),
+    Free(usize),
+}
+
+pub struct Disk {
+    entries: Vec<DiskEntry>,
+    next_id: usize,
+}
+
+impl Disk {
+    fn new() -> Self {
+        Self {
+            entries: vec![],
+            next_id: 0,
+        }
+    }
+
+    fn add_entry(&mut self, size: usize, is_file: bool) {
+        let entry = if is_file {
+            DiskEntry::File(FileBlock {
+                id: self.next_id,
+                size,
+            })
+        } else {
+            DiskEntry::Free(size)
+        };
+
+        if is_file {
+            self.next_id += 1;
+        }
+        self.entries.push(entry);
+    }
+
+    fn layout(&self) -> Vec<Option<usize>> {
+        self.entries
+            .iter()
+            .flat_map(|entry| match entry {
+                DiskEntry::File(file) => vec![Some(file.id); file.size],
+                DiskEntry::Free(size) => vec![None; *size],
+            })
+            .collect()
+    }
+
+    fn defragmented(&self) -> Vec<Option<usize>> {
+        let mut layout = self.layout();
+        for i in (0..layout.len()).rev() {
+            layout[i].and_then(|id| {
+                (0..i).find(|&j| layout[j].is_none()).map(|free_idx| {
+                    layout[free_idx] = Some(id);
+                    layout[i] = None;
+                })
+            });
+        }
+
+        layout
+    }
+
+    fn defragmented_keep_files(&self) -> Vec<Option<usize>> {
+        let mut files = Vec::new();
+        let mut position = 0;
+        for entry in &self.entries {
+            match entry {
+                DiskEntry::File(file) => {
+                    files.push((file.clone(), position));
+                    position += file.size;
+                }
+                DiskEntry::Free(size) => position += size,
+            }
+        }
+
+        let mut occupied: RangeSet<usize> = files
+            .iter()
+            .map(|(file, start)| *start..start + file.size)
+            .collect();
+        for (file, start) in files.iter_mut().rev() {
+            if let Some(free_start) = occupied.first_gap(file.size, 0..*start) {
+                occupied.remove(*start..*start + file.size);
+                occupied.insert(free_start..free_start + file.size);
+                *start = free_start;
+            }
+        }
+
+        let mut layout = vec![None; position];
+        for (file, start) in files {
+            layout[start..start + file.size].fill(Some(file.id));
+        }
+        layout
+    }
+}
+
+fn calculate_checksum(layout: &[Option<usize>]) -> usize {
+    layout
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, &id)| id.map(|id| pos * id))
+        .sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Disk;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut disk = Disk::new();
+        for (i, (offset, c)) in input.trim_end().char_indices().enumerate() {
+            let Some(size) = c.to_digit(10) else {
+                return Err(ParseError::at_offset(
+                    input,
+                    offset,
+                    c.len_utf8(),
+                    "a digit",
+                ));
+            };
+            disk.add_entry(size as usize, i % 2 == 0);
+        }
+        Ok(disk)
+    }
+
+    fn part1(disk: &Self::Input) -> impl std::fmt::Display {
+        calculate_checksum(&disk.defragmented())
+    }
+
+    fn part2(disk: &Self::Input) -> impl std::fmt::Display {
+        calculate_checksum(&disk.defragmented_keep_files())
+    }
+}
+
+aoc::examples!(Day09 {
+    example_1: "examples/example-1.txt", part1 = 1928, part2 = 2858;
+});
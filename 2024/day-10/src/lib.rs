@@ -0,0 +1,80 @@
+use aoc::{
+    check::Check, search, Direction, FxHashSet as HashSet, Grid, ParseError, Point, Solution,
+};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Debug)]
+pub struct Map {
+    grid: Grid<i32>,
+    trailheads: Vec<Point>,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        // `.` in the examples is impassable
+        let check = Check::grid().chars("0123456789.");
+        let grid = Grid::parse_checked(input, check, |c| c.to_digit(10).map_or(-1, |d| d as i32))?;
+        let trailheads = grid.positions_of(&0).collect();
+
+        Ok(Self { grid, trailheads })
+    }
+
+    /// Every trail climbs one step at a time, so all trails from a trailhead have the same length
+    /// and are exactly the shortest paths to the reachable 9s.
+    fn find_paths(&self, start: Point) -> (usize, usize) {
+        let Some((_, trails)) = search::bfs_all(
+            start,
+            |&pos| {
+                let next_value = self.grid[pos] + 1;
+                Direction::ALL_BASIC
+                    .into_iter()
+                    .map(move |dir| pos + dir)
+                    .filter(move |&next| self.grid.get(next) == Some(&next_value))
+            },
+            |&pos| self.grid[pos] == 9,
+        ) else {
+            return (0, 0);
+        };
+
+        let endpoints: HashSet<Point> = trails
+            .iter()
+            .filter_map(|trail| trail.last())
+            .copied()
+            .collect();
+        (endpoints.len(), trails.len())
+    }
+
+    fn n_valid_paths(&self) -> (usize, usize) {
+        self.trailheads
+            .par_iter()
+            .map(|&start| self.find_paths(start))
+            .reduce(
+                || (0, 0),
+                |(acc_paths, acc_paths2), (paths, paths2)| (acc_paths + paths, acc_paths2 + paths2),
+            )
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Self::Input) -> impl std::fmt::Display {
+        map.n_valid_paths().0
+    }
+
+    fn part2(map: &Self::Input) -> impl std::fmt::Display {
+        map.n_valid_paths().1
+    }
+}
+
+aoc::examples!(Day10 {
+    example_1: "examples/example-1.txt", part1 = 36, part2 = 81;
+});
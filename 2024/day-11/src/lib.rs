@@ -0,0 +1,79 @@
+use aoc::{parse, FxHashMap as HashMap, ParseError, Solution};
+use std::{num::ParseIntError, str::FromStr};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Stone(u64);
+
+impl Stone {
+    fn split(&self) -> Vec<Stone> {
+        match self.0 {
+            0 => vec![Stone(1)],
+            n if n.to_string().len() % 2 == 0 => {
+                let s = n.to_string();
+                let (left, right) = s.split_at(s.len() / 2);
+                vec![Stone(left.parse().unwrap()), Stone(right.parse().unwrap())]
+            }
+            _ => vec![Stone(self.0 * 2024)],
+        }
+    }
+}
+
+fn blink(stones: &HashMap<Stone, u64>) -> HashMap<Stone, u64> {
+    let mut new_stones = HashMap::default();
+    for (&stone, &count) in stones.iter() {
+        for new_stone in stone.split() {
+            new_stones
+                .entry(new_stone)
+                .and_modify(|c| *c += count)
+                .or_insert(count);
+        }
+    }
+    new_stones
+}
+
+/// The number of stones after blinking `times` times
+fn count_after(stones: &HashMap<Stone, u64>, times: usize) -> u64 {
+    (0..times)
+        .fold(stones.clone(), |stones_acc, _| blink(&stones_acc))
+        .values()
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// The count of every distinct stone, their order never matters
+    type Input = HashMap<Stone, u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut stones_map: HashMap<Stone, u64> = HashMap::default();
+        for line in parse::lines(input) {
+            for stone in line.numbers()? {
+                *stones_map.entry(stone).or_insert(0) += 1;
+            }
+        }
+        Ok(stones_map)
+    }
+
+    fn part1(stones: &Self::Input) -> impl std::fmt::Display {
+        count_after(stones, 25)
+    }
+
+    fn part2(stones: &Self::Input) -> impl std::fmt::Display {
+        count_after(stones, 75)
+    }
+}
+
+impl FromStr for Stone {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+aoc::examples!(Day11 {
+    example_1: "examples/example-1.txt", part1 = 55312;
+});
@@ -0,0 +1,38 @@
+use aoc::{check::Check, Connectivity, Grid, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let check = Check::grid().chars_matching("a plant letter", |c| c.is_ascii_uppercase());
+        Grid::parse_checked(input, check, |c| c)
+    }
+
+    fn part1(garden: &Self::Input) -> impl std::fmt::Display {
+        garden
+            .regions(Connectivity::Four, |a, b| a == b)
+            .iter()
+            .map(|r| r.area() * r.perimeter())
+            .sum::<usize>()
+    }
+
+    fn part2(garden: &Self::Input) -> impl std::fmt::Display {
+        garden
+            .regions(Connectivity::Four, |a, b| a == b)
+            .iter()
+            .map(|r| r.area() * r.sides())
+            .sum::<usize>()
+    }
+}
+
+aoc::examples!(Day12 {
+    example_1: "examples/example-1.txt", part1 = 140, part2 = 80;
+    example_2: "examples/example-2.txt", part1 = 772, part2 = 436;
+    example_3: "examples/example-3.txt", part1 = 1930, part2 = 1206;
+    example_4: "examples/example-4.txt", part2 = 236;
+    example_5: "examples/example-5.txt", part2 = 368;
+});
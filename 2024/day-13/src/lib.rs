@@ -0,0 +1,175 @@
+use aoc::{
+    linalg::{self, Solutions},
+    math,
+    parse::{self, Line},
+    scan, ParseError, Solution,
+};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Debug)]
+struct Button {
+    id: char,
+    dx: u64,
+    dy: u64,
+}
+
+impl Button {
+    fn cost(&self) -> u64 {
+        match self.id {
+            'A' => 3,
+            'B' => 1,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    buttons: Vec<Button>,
+    price_pos: (u64, u64),
+}
+
+impl Machine {
+    fn new(lines: &[Line]) -> Result<Self, ParseError> {
+        let [a, b, price] = lines else {
+            let first = lines[0];
+            return Err(first
+                .error(0, first.text.len(), "two buttons and a prize")
+                .with_reason(format!("the machine has {} lines", lines.len())));
+        };
+
+        let (dx, dy) = scan!(a, "Button A: X+{}, Y+{}")?;
+        let a = Button { id: 'A', dx, dy };
+        let (dx, dy) = scan!(b, "Button B: X+{}, Y+{}")?;
+        let b = Button { id: 'B', dx, dy };
+
+        Ok(Machine {
+            buttons: vec![a, b],
+            price_pos: scan!(price, "Prize: X={}, Y={}")?,
+        })
+    }
+
+    fn solve(&self) -> Option<(u64, u64)> {
+        for a in 0..=100 {
+            for b in 0..=100 {
+                let x = a * self.buttons[0].dx + b * self.buttons[1].dx;
+                let y = a * self.buttons[0].dy + b * self.buttons[1].dy;
+
+                if x == self.price_pos.0 && y == self.price_pos.1 {
+                    return Some((a, b));
+                }
+            }
+        }
+        None
+    }
+
+    fn solve_part_2(&self) -> Option<(u64, u64)> {
+        let n_to_add = 10_000_000_000_000;
+        let (a, b) = (&self.buttons[0], &self.buttons[1]);
+        let target = (
+            self.price_pos.0 as i128 + n_to_add,
+            self.price_pos.1 as i128 + n_to_add,
+        );
+
+        let presses = match linalg::solve(
+            &[
+                vec![a.dx as i128, b.dx as i128],
+                vec![a.dy as i128, b.dy as i128],
+            ],
+            &[target.0, target.1],
+        ) {
+            Solutions::Unique(presses) => (presses[0].to_integer()?, presses[1].to_integer()?),
+            Solutions::Inconsistent => return None,
+            // Both buttons move along the same line as the prize, any equation with a non-zero
+            // coefficient describes every solution
+            Solutions::Infinite if a.dx != 0 || b.dx != 0 => {
+                self.cheapest_on_line(a.dx as i128, b.dx as i128, target.0)?
+            }
+            Solutions::Infinite => self.cheapest_on_line(a.dy as i128, b.dy as i128, target.1)?,
+        };
+
+        (presses.0 >= 0 && presses.1 >= 0).then_some((presses.0 as u64, presses.1 as u64))
+    }
+
+    /// Cheapest non-negative `(a, b)` with `a * p + b * q == target`
+    fn cheapest_on_line(&self, p: i128, q: i128, target: i128) -> Option<(i128, i128)> {
+        let cost = |(a, b): (i128, i128)| {
+            a * self.buttons[0].cost() as i128 + b * self.buttons[1].cost() as i128
+        };
+        if p == 0 || q == 0 {
+            // Only one button moves, pressing the other one is wasted
+            let (presses, step) = if p == 0 { (1, q) } else { (0, p) };
+            let n = (target % step == 0).then_some(target / step)?;
+            return Some(if presses == 0 { (n, 0) } else { (0, n) });
+        }
+
+        let (g, x, y) = math::ext_gcd(p, q);
+        if target % g != 0 {
+            return None;
+        }
+
+        // Every solution is (a0 + k * q / g, b0 - k * p / g), keep both non-negative
+        let (a0, b0) = (x * (target / g), y * (target / g));
+        let (step_a, step_b) = (q / g, p / g);
+        let k_min = (-a0).div_euclid(step_a) + i128::from((-a0).rem_euclid(step_a) != 0);
+        let k_max = b0.div_euclid(step_b);
+        if k_min > k_max {
+            return None;
+        }
+
+        // The cost changes linearly with k, so the cheapest solution is at one of the ends
+        [k_min, k_max]
+            .map(|k| (a0 + k * step_a, b0 - k * step_b))
+            .into_iter()
+            .min_by_key(|&presses| cost(presses))
+    }
+
+    fn calculate_cost(&self, presses: (u64, u64)) -> u64 {
+        presses.0 * self.buttons[0].cost() + presses.1 * self.buttons[1].cost()
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::paragraphs(input)
+            .iter()
+            .map(|lines| Machine::new(lines))
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> impl std::fmt::Display {
+        solve(machines, false).1
+    }
+
+    fn part2(machines: &Self::Input) -> impl std::fmt::Display {
+        solve(machines, true).1
+    }
+}
+
+/// Returns `(winnable_count, total_cost)`
+fn solve(machines: &[Machine], part2: bool) -> (u64, u64) {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            let solver = if part2 {
+                Machine::solve_part_2
+            } else {
+                Machine::solve
+            };
+
+            solver(machine).map(|presses| machine.calculate_cost(presses))
+        })
+        .fold((0, 0), |acc, cost| (acc.0 + 1, acc.1 + cost))
+}
+
+aoc::examples!(Day13 {
+    example_1: "examples/example-1.txt", part1 = 480;
+    // Buttons moving along the same diagonal as the prize, where B is the cheaper way to cover
+    // ground in the first machine and A in the second
+    collinear: "examples/collinear.txt", part1 = 5, part2 = 6_666_666_666_675u64;
+});
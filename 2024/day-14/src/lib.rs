@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use aoc::{math, parse, scan, ParseError, Point, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Debug, Clone)]
+struct Robot {
+    position: Point,
+    velocity: (isize, isize),
+}
+
+#[derive(Clone)]
+pub struct Arena {
+    robots: Vec<Robot>,
+    width: isize,
+    height: isize,
+}
+
+impl Arena {
+    /// Parses the robots of a `width` by `height` arena, they always start inside it
+    pub fn parse(input: &str, width: isize, height: isize) -> Result<Self, ParseError> {
+        let robots = parse::lines(input.trim_end())
+            .map(|line| {
+                let (x, y, dx, dy) = scan!(line, "p={},{} v={},{}")?;
+                Ok(Robot {
+                    position: Point::new(x, y),
+                    velocity: (dx, dy),
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Ok(Arena {
+            robots,
+            width,
+            height,
+        })
+    }
+
+    /// Moves every robot `steps` seconds ahead
+    pub fn step(&mut self, steps: isize) {
+        for robot in self.robots.iter_mut() {
+            robot.position += (robot.velocity.0 * steps, robot.velocity.1 * steps);
+            robot.position.wrap_around(self.width, self.height);
+        }
+    }
+
+    fn safety_factor(&self) -> usize {
+        let mid_x = self.width / 2;
+        let mid_y = self.height / 2;
+
+        let mut quadrants = [0; 4];
+        for robot in &self.robots {
+            if robot.position.x == mid_x || robot.position.y == mid_y {
+                continue;
+            }
+
+            let quadrant = match (robot.position.x < mid_x, robot.position.y < mid_y) {
+                (true, true) => 0,   // Top-left
+                (false, true) => 1,  // Top-right
+                (true, false) => 2,  // Bottom-left
+                (false, false) => 3, // Bottom-right
+            };
+            quadrants[quadrant] += 1;
+        }
+
+        quadrants.iter().product()
+    }
+
+    /// The x coordinates repeat every `width` steps and the y coordinates every `height` steps.
+    /// The tree is drawn when both are clustered the tightest, which pins the step down modulo
+    /// each period, so the Chinese remainder theorem gives the first step where both line up.
+    pub fn christmas_tree_step(&self) -> isize {
+        let spread = |period: isize, coordinate: fn(&Robot) -> (isize, isize)| {
+            (0..period)
+                .min_by_key(|&steps| {
+                    let values: Vec<isize> = self
+                        .robots
+                        .iter()
+                        .map(|robot| {
+                            let (position, velocity) = coordinate(robot);
+                            (position + velocity * steps).rem_euclid(period)
+                        })
+                        .collect();
+                    let n = values.len() as isize;
+                    let sum: isize = values.iter().sum();
+                    // Variance scaled by n², keeps everything in integers
+                    n * values.iter().map(|v| v * v).sum::<isize>() - sum * sum
+                })
+                .unwrap()
+        };
+
+        let x_steps = spread(self.width, |r| (r.position.x, r.velocity.0));
+        let y_steps = spread(self.height, |r| (r.position.y, r.velocity.1));
+        math::crt([(x_steps, self.width), (y_steps, self.height)])
+            .unwrap()
+            .0
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Arena;
+
+    /// Every real input is in a 101x103 arena
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Arena::parse(input, 101, 103)
+    }
+
+    fn part1(arena: &Self::Input) -> impl std::fmt::Display {
+        let mut arena = arena.clone();
+        arena.step(100);
+        arena.safety_factor()
+    }
+
+    fn part2(arena: &Self::Input) -> impl std::fmt::Display {
+        arena.christmas_tree_step()
+    }
+}
+
+impl std::fmt::Display for Arena {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let robots_per_position: HashMap<_, _> =
+            self.robots
+                .iter()
+                .map(|r| &r.position)
+                .fold(HashMap::new(), |mut counts, pos| {
+                    *counts.entry(pos).or_insert(0) += 1;
+                    counts
+                });
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match robots_per_position.get(&Point::new(x, y)) {
+                    Some(&count) => write!(f, "{}", count)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The puzzle's examples are in an 11x7 arena instead
+#[cfg(test)]
+struct Day14Example;
+
+#[cfg(test)]
+impl Solution for Day14Example {
+    type Input = Arena;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Arena::parse(input, 11, 7)
+    }
+
+    fn part1(arena: &Self::Input) -> impl std::fmt::Display {
+        Day14::part1(arena)
+    }
+
+    fn part2(arena: &Self::Input) -> impl std::fmt::Display {
+        Day14::part2(arena)
+    }
+}
+
+aoc::examples!(Day14Example {
+    example_1: "examples/example-1.txt", part1 = 12;
+});
@@ -0,0 +1,247 @@
+use std::fmt::Display;
+
+use aoc::{check::Check, parse, Direction, Grid, ParseError, Point, Solution};
+
+pub const INPUT: &str = include_str!("../input1.txt");
+
+#[derive(Clone)]
+pub struct Warehouse {
+    grid: Grid<char>,
+    robot: Point,
+}
+
+impl Warehouse {
+    fn new(input: &str) -> Result<Option<Self>, ParseError> {
+        let grid = Grid::parse_checked(input, Check::grid().chars("#.O@"), |c| c)?;
+        let Some(robot) = grid.find(|&c| c == '@') else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self { grid, robot }))
+    }
+
+    fn move_robot(&mut self, direction: &Direction) {
+        self.grid[self.robot] = '.';
+        self.robot += *direction;
+        self.grid[self.robot] = '@';
+    }
+
+    fn move_box(&mut self, pos: &Point, direction: &Direction) {
+        let new_pos = *pos + *direction;
+        match self.grid[new_pos] {
+            '#' => (),
+            'O' => {
+                self.move_box(&new_pos, direction);
+                // If the next position is now empty (meaning boxes were successfully moved)
+                if self.grid[new_pos] == '.' {
+                    self.grid.swap(*pos, new_pos);
+                }
+            }
+            _ => self.grid.swap(*pos, new_pos),
+        }
+    }
+
+    /// If robot is blocked by box, move the box along with the robot if possible.
+    fn run_path(&mut self, path: &[Direction]) {
+        for direction in path {
+            let next_pos = self.robot + *direction;
+            match self.grid[next_pos] {
+                '#' => continue,
+                'O' => {
+                    self.move_box(&next_pos, direction);
+                    if self.grid[next_pos] == '.' {
+                        self.move_robot(direction);
+                    }
+                }
+                _ => self.move_robot(direction),
+            }
+        }
+    }
+
+    fn run_path_part_2(&mut self, path: &[Direction]) {
+        for direction in path {
+            let next_pos = self.robot + *direction;
+            let left_pos = match self.grid[next_pos] {
+                '#' => continue,
+                '[' => next_pos,
+                ']' => next_pos + Direction::Left,
+                _ => {
+                    self.move_robot(direction);
+                    continue;
+                }
+            };
+
+            if self.can_move_wide_box(&left_pos, direction) {
+                self.move_wide_box(&left_pos, direction);
+                self.move_robot(direction);
+            }
+        }
+    }
+
+    /// The cells a wide box would move into, excluding the ones it already occupies.
+    fn wide_box_targets(left_pos: &Point, direction: &Direction) -> Vec<Point> {
+        match direction {
+            Direction::Left => vec![*left_pos + Direction::Left],
+            Direction::Right => vec![*left_pos + Direction::Right + Direction::Right],
+            _ => vec![
+                *left_pos + *direction,
+                *left_pos + Direction::Right + *direction,
+            ],
+        }
+    }
+
+    fn can_move_wide_box(&self, left_pos: &Point, direction: &Direction) -> bool {
+        Self::wide_box_targets(left_pos, direction)
+            .iter()
+            .all(|&target| match self.grid[target] {
+                '#' => false,
+                '[' => self.can_move_wide_box(&target, direction),
+                ']' => self.can_move_wide_box(&(target + Direction::Left), direction),
+                _ => true,
+            })
+    }
+
+    /// Expects `can_move_wide_box` to have been checked first.
+    fn move_wide_box(&mut self, left_pos: &Point, direction: &Direction) {
+        for target in Self::wide_box_targets(left_pos, direction) {
+            match self.grid[target] {
+                '[' => self.move_wide_box(&target, direction),
+                ']' => self.move_wide_box(&(target + Direction::Left), direction),
+                _ => (),
+            }
+        }
+
+        let right_pos = *left_pos + Direction::Right;
+        self.grid[*left_pos] = '.';
+        self.grid[right_pos] = '.';
+        self.grid[*left_pos + *direction] = '[';
+        self.grid[right_pos + *direction] = ']';
+    }
+
+    fn widen_grid(&mut self) {
+        let width = self.grid.width() as usize * 2;
+        let height = self.grid.height() as usize;
+        let cells = self
+            .grid
+            .values()
+            .flat_map(|c| match c {
+                '#' => ['#', '#'],
+                'O' => ['[', ']'],
+                '@' => ['@', '.'],
+                _ => ['.', '.'],
+            })
+            .collect();
+
+        self.grid = Grid::new(width, height, cells);
+        self.robot.x *= 2;
+    }
+
+    fn gps_sum(&self) -> isize {
+        self.grid
+            .cells()
+            .filter(|(_, &c)| c == 'O' || c == '[')
+            .map(|(point, _)| 100 * point.y + point.x)
+            .sum()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    /// The warehouse and the robot's moves
+    type Input = (Warehouse, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [grid, moves] = parse::paragraphs_exact(input)?;
+
+        let mut path = Vec::new();
+        for line in &moves {
+            for (i, c) in line.text.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+                // `Direction` also parses letters and diagonal arrows, the robot only gets these
+                let direction = Direction::try_from(c)
+                    .ok()
+                    .filter(|_| "^>v<".contains(c))
+                    .ok_or_else(|| {
+                        line.error(i, i + c.len_utf8(), "one of `^`, `>`, `v` or `<`")
+                    })?;
+                path.push(direction);
+            }
+        }
+
+        let map = grid
+            .iter()
+            .map(|line| line.text)
+            .collect::<Vec<_>>()
+            .join("\n");
+        // The grid's errors count lines from the start of the map, not of the input
+        let offset = grid[0].number - 1;
+        let warehouse = Warehouse::new(&map).map_err(|error| ParseError {
+            line: error.line + offset,
+            ..error
+        })?;
+        let Some(warehouse) = warehouse else {
+            let last = grid.last().unwrap();
+            let end = last.text.len();
+            return Err(last.error(end, end, "a robot `@` in the warehouse"));
+        };
+        Ok((warehouse, path))
+    }
+
+    fn part1((warehouse, path): &Self::Input) -> impl std::fmt::Display {
+        let mut warehouse = warehouse.clone();
+        warehouse.run_path(path);
+        warehouse.gps_sum()
+    }
+
+    fn part2((warehouse, path): &Self::Input) -> impl std::fmt::Display {
+        let mut warehouse = warehouse.clone();
+        warehouse.widen_grid();
+        warehouse.run_path_part_2(path);
+        warehouse.gps_sum()
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+aoc::examples!(Day15 {
+    example_1: "examples/example-1.txt", part1 = 10092, part2 = 9021;
+    example_2: "examples/example-2.txt", part2 = 618;
+    example_3: "examples/example-3.txt", part1 = 2028;
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_errors_point_at_the_input_line() {
+        let error = Day15::parse("\n\n####\n#@.#\n#.x#\n####\n\n<>\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (5, 3, "x")
+        );
+
+        let error = Day15::parse("####\n#..#\n####\n\n<>\n").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 5));
+        assert_eq!(error.expected, "a robot `@` in the warehouse");
+    }
+
+    #[test]
+    fn moves_are_only_the_four_arrows() {
+        for (moves, column, snippet) in [("<^U>", 3, "U"), ("<↗>", 2, "↗")] {
+            let input = format!("####\n#@.#\n####\n\n{moves}\n");
+            let error = Day15::parse(&input).err().unwrap();
+            assert_eq!(
+                (error.line, error.column, error.snippet.as_str()),
+                (5, column, snippet)
+            );
+            assert_eq!(error.expected, "one of `^`, `>`, `v` or `<`");
+        }
+    }
+}
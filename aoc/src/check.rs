@@ -0,0 +1,328 @@
+//! Sanity checks for puzzle inputs, catching what editors and copy-pasting do to them before a
+//! parser trips over it somewhere confusing. [`Check::report`] lists everything that's off,
+//! [`Check::normalize`] fixes what it can and fails on the rest.
+
+use std::{borrow::Cow, fmt};
+
+use crate::{parse, ParseError};
+
+/// Something off about an input, at a 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub line: usize,
+    pub column: usize,
+    pub kind: IssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The input starts with a byte order mark
+    Bom,
+    /// The line ends with `\r\n`
+    CrLf,
+    /// Spaces or tabs at the end of a line, or blank lines at the end of the input
+    TrailingWhitespace,
+    /// A grid row that isn't as wide as the first one
+    RaggedRow {
+        width: usize,
+        expected: usize,
+    },
+    UnexpectedChar(char),
+}
+
+impl Issue {
+    /// Whether [`Check::normalize`] fixes it
+    pub fn is_fixable(&self) -> bool {
+        matches!(
+            self.kind,
+            IssueKind::Bom | IssueKind::CrLf | IssueKind::TrailingWhitespace
+        )
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            IssueKind::Bom => write!(f, "starts with a byte order mark"),
+            IssueKind::CrLf => write!(f, "ends with `\\r\\n`"),
+            IssueKind::TrailingWhitespace => write!(f, "trailing whitespace"),
+            IssueKind::RaggedRow { width, expected } => {
+                write!(f, "row is {width} wide, the first one is {expected}")
+            }
+            IssueKind::UnexpectedChar(c) => write!(f, "unexpected {c:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Allowed {
+    Any,
+    Chars(&'static str),
+    Matching(&'static str, fn(char) -> bool),
+}
+
+/// What an input is supposed to look like
+#[derive(Debug, Clone, Copy)]
+pub struct Check {
+    grid: bool,
+    allowed: Allowed,
+}
+
+impl Check {
+    /// Lines of anything
+    pub fn lines() -> Self {
+        Self {
+            grid: false,
+            allowed: Allowed::Any,
+        }
+    }
+
+    /// Rows that are all as wide as the first one
+    pub fn grid() -> Self {
+        Self {
+            grid: true,
+            ..Self::lines()
+        }
+    }
+
+    /// Only these characters may appear, besides line endings
+    pub fn chars(self, allowed: &'static str) -> Self {
+        Self {
+            allowed: Allowed::Chars(allowed),
+            ..self
+        }
+    }
+
+    /// Only characters matching `allowed` may appear, `description` says which in errors
+    pub fn chars_matching(self, description: &'static str, allowed: fn(char) -> bool) -> Self {
+        Self {
+            allowed: Allowed::Matching(description, allowed),
+            ..self
+        }
+    }
+
+    /// Every issue with the input, in order
+    pub fn report(&self, input: &str) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let issue = |line, column, kind| Issue { line, column, kind };
+        if input.starts_with('\u{feff}') {
+            issues.push(issue(1, 1, IssueKind::Bom));
+        }
+
+        let content_lines = input.trim_end().lines().count();
+        let mut width = None;
+        for (i, raw) in input.split_inclusive('\n').enumerate() {
+            let number = i + 1;
+            let line = raw.strip_suffix('\n').unwrap_or(raw);
+            // Only the start of the input can have a byte order mark, anywhere else it's content
+            let line = match i {
+                0 => line.strip_prefix('\u{feff}').unwrap_or(line),
+                _ => line,
+            };
+            let (line, crlf) = match line.strip_suffix('\r') {
+                Some(line) => (line, true),
+                None => (line, false),
+            };
+            let text = line.trim_end();
+
+            for (column, c) in text.chars().enumerate() {
+                if !self.allows(c) {
+                    issues.push(issue(number, column + 1, IssueKind::UnexpectedChar(c)));
+                }
+            }
+            if number > content_lines {
+                issues.push(issue(number, 1, IssueKind::TrailingWhitespace));
+                continue;
+            }
+            if text.len() < line.len() {
+                let column = text.chars().count() + 1;
+                issues.push(issue(number, column, IssueKind::TrailingWhitespace));
+            }
+            if crlf {
+                let column = line.chars().count() + 1;
+                issues.push(issue(number, column, IssueKind::CrLf));
+            }
+            if self.grid {
+                let row = text.chars().count();
+                match width {
+                    None => width = Some(row),
+                    Some(expected) if row != expected => {
+                        let kind = IssueKind::RaggedRow {
+                            width: row,
+                            expected,
+                        };
+                        issues.push(issue(number, row.min(expected) + 1, kind));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        issues
+    }
+
+    /// Strips a byte order mark, `\r`s and trailing whitespace, then fails on the first issue
+    /// that can't be fixed, like a ragged row or an unexpected character. Line numbers stay the
+    /// same, so errors still point at the right place in the original.
+    pub fn normalize<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ParseError> {
+        let issues = self.report(input);
+        let input = if issues.iter().any(Issue::is_fixable) {
+            let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+            let mut fixed: String = input
+                .trim_end()
+                .lines()
+                .flat_map(|line| [line.trim_end(), "\n"])
+                .collect();
+            if !input.trim_end().is_empty() && !input.ends_with('\n') {
+                fixed.pop();
+            }
+            Cow::Owned(fixed)
+        } else {
+            Cow::Borrowed(input)
+        };
+
+        let Some(issue) = issues.into_iter().find(|issue| !issue.is_fixable()) else {
+            return Ok(input);
+        };
+        let line = parse::lines(&input).nth(issue.line - 1).unwrap();
+        let start = line
+            .text
+            .char_indices()
+            .nth(issue.column - 1)
+            .map_or(line.text.len(), |(i, _)| i);
+        Err(match issue.kind {
+            IssueKind::RaggedRow { width, expected } => line
+                .error(start, line.text.len(), format!("a row {expected} wide"))
+                .with_reason(format!("it's {width} wide")),
+            IssueKind::UnexpectedChar(c) => {
+                line.error(start, start + c.len_utf8(), self.expected())
+            }
+            _ => unreachable!("fixable issues were skipped"),
+        })
+    }
+
+    fn allows(&self, c: char) -> bool {
+        match self.allowed {
+            Allowed::Any => true,
+            Allowed::Chars(chars) => chars.contains(c),
+            Allowed::Matching(_, matches) => matches(c),
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self.allowed {
+            Allowed::Any => "anything".to_string(),
+            Allowed::Chars(chars) => {
+                let chars: Vec<_> = chars.chars().map(|c| format!("`{c}`")).collect();
+                format!("one of {}", chars.join(", "))
+            }
+            Allowed::Matching(description, _) => description.to_string(),
+        }
+    }
+}
+
+/// The first line where two copies of an input differ, ignoring line endings and trailing
+/// whitespace, or `None` if they're the same
+pub fn first_difference(a: &str, b: &str) -> Option<usize> {
+    let lines = |input: &'_ str| -> Vec<String> {
+        let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        input
+            .trim_end()
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    };
+    let (a, b) = (lines(a), lines(b));
+    (0..a.len().max(b.len()))
+        .find(|&i| a.get(i) != b.get(i))
+        .map(|i| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(issues: &[Issue]) -> Vec<(usize, usize, IssueKind)> {
+        issues
+            .iter()
+            .map(|issue| (issue.line, issue.column, issue.kind.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn reports_every_issue_with_its_position() {
+        let input = "\u{feff}#.^\r\n#x. \r\n##\n\n";
+        let issues = Check::grid().chars("#.^").report(input);
+        assert_eq!(
+            kinds(&issues),
+            [
+                (1, 1, IssueKind::Bom),
+                (1, 4, IssueKind::CrLf),
+                (2, 2, IssueKind::UnexpectedChar('x')),
+                (2, 4, IssueKind::TrailingWhitespace),
+                (2, 5, IssueKind::CrLf),
+                (
+                    3,
+                    3,
+                    IssueKind::RaggedRow {
+                        width: 2,
+                        expected: 3
+                    }
+                ),
+                (4, 1, IssueKind::TrailingWhitespace),
+            ]
+        );
+    }
+
+    #[test]
+    fn byte_order_marks_only_start_the_input() {
+        let check = Check::grid().chars("#.");
+        let issues = check.report("#.\n\u{feff}#\n#\u{feff}\n");
+        assert_eq!(
+            kinds(&issues),
+            [
+                (2, 1, IssueKind::UnexpectedChar('\u{feff}')),
+                (3, 2, IssueKind::UnexpectedChar('\u{feff}')),
+            ]
+        );
+        let error = check.normalize("#.\n#\u{feff}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn normalizes_what_it_can() {
+        let check = Check::grid().chars("#.");
+        assert_eq!(check.normalize("#.#\n.#.\n").unwrap(), "#.#\n.#.\n");
+        assert!(matches!(check.normalize("#.#\n"), Ok(Cow::Borrowed(_))));
+        assert_eq!(
+            check.normalize("\u{feff}#.# \r\n.#.\r\n\r\n").unwrap(),
+            "#.#\n.#.\n"
+        );
+        assert_eq!(check.normalize("#.#\n.#.").unwrap(), "#.#\n.#.");
+    }
+
+    #[test]
+    fn fails_on_what_it_cant_fix() {
+        let check = Check::grid().chars("#.");
+        let error = check.normalize("#.#\r\n#.\r\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "a row 3 wide");
+
+        let error = check.normalize("#.#\n#o#\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (2, 2, "o")
+        );
+        assert_eq!(error.expected, "one of `#`, `.`");
+
+        let check = Check::lines().chars_matching("a digit", |c| c.is_ascii_digit());
+        assert_eq!(check.normalize("12\n3a\n").unwrap_err().expected, "a digit");
+    }
+
+    #[test]
+    fn copies_differ_where_their_lines_do() {
+        assert_eq!(first_difference("a\nb\n", "a\r\nb  \n\n"), None);
+        assert_eq!(first_difference("a\nb\n", "a\nc\n"), Some(2));
+        assert_eq!(first_difference("a\nb\n", "a\n"), Some(2));
+    }
+}
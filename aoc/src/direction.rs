@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
@@ -37,6 +39,76 @@ impl Direction {
         Direction::DownLeft,
         Direction::UpLeft,
     ];
+
+    /// All eight directions in clockwise order, starting with `Up`
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Rotates clockwise by the given number of 45° steps, relies on the variants being declared
+    /// in clockwise order
+    fn rotate(&self, steps: usize) -> Self {
+        Self::ALL[(*self as usize + steps) % 8]
+    }
+
+    /// Rotates 90° clockwise
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotates 90° counter-clockwise
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Rotates 45° clockwise
+    pub fn rotate_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        Self::ALL_DIAGONAL.contains(self)
+    }
+
+    /// Returns the character for the direction, letters only exist for the basic directions
+    pub fn to_char(&self, style: CharStyle) -> Option<char> {
+        let c = match (style, self) {
+            (CharStyle::Arrow, Direction::Up) => '^',
+            (CharStyle::Arrow, Direction::UpRight) => '↗',
+            (CharStyle::Arrow, Direction::Right) => '>',
+            (CharStyle::Arrow, Direction::DownRight) => '↘',
+            (CharStyle::Arrow, Direction::Down) => 'v',
+            (CharStyle::Arrow, Direction::DownLeft) => '↙',
+            (CharStyle::Arrow, Direction::Left) => '<',
+            (CharStyle::Arrow, Direction::UpLeft) => '↖',
+            (CharStyle::Letter, Direction::Up) => 'U',
+            (CharStyle::Letter, Direction::Right) => 'R',
+            (CharStyle::Letter, Direction::Down) => 'D',
+            (CharStyle::Letter, Direction::Left) => 'L',
+            (CharStyle::Letter, _) => return None,
+        };
+        Some(c)
+    }
+}
+
+/// How `Direction::to_char` renders a direction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CharStyle {
+    /// `^`, `>`, `v` and `<` as used in the puzzle inputs, plus arrows for diagonals
+    Arrow,
+    /// `U`, `R`, `D` and `L`
+    Letter,
 }
 
 impl std::ops::Add<Direction> for (isize, isize) {
@@ -55,14 +127,97 @@ impl std::ops::Sub<Direction> for (isize, isize) {
     }
 }
 
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            'U' | '^' => Direction::Up,
-            'R' | '>' => Direction::Right,
-            'D' | 'v' => Direction::Down,
-            'L' | '<' => Direction::Left,
-            _ => panic!("Invalid direction character: {}", c),
+            'U' | '^' => Ok(Direction::Up),
+            'R' | '>' => Ok(Direction::Right),
+            'D' | 'v' => Ok(Direction::Down),
+            'L' | '<' => Ok(Direction::Left),
+            '↗' => Ok(Direction::UpRight),
+            '↘' => Ok(Direction::DownRight),
+            '↙' => Ok(Direction::DownLeft),
+            '↖' => Ok(Direction::UpLeft),
+            _ => Err(ParseDirectionError(c)),
         }
     }
 }
+
+/// The character that couldn't be parsed as a `Direction`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(pub char);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid direction character: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_follow_the_steps() {
+        for dir in Direction::ALL {
+            // Clockwise on screen, where y grows downwards
+            let (dx, dy) = dir.as_step();
+            assert_eq!(dir.turn_right().as_step(), (-dy, dx), "{dir:?}");
+            assert_eq!(dir.turn_left().as_step(), (dy, -dx), "{dir:?}");
+            assert_eq!(dir.opposite().as_step(), (-dx, -dy), "{dir:?}");
+        }
+        assert_eq!(Direction::Up.rotate_45(), Direction::UpRight);
+        assert_eq!(Direction::UpLeft.rotate_45(), Direction::Up);
+    }
+
+    #[test]
+    fn full_turns_return_to_the_start() {
+        for dir in Direction::ALL {
+            assert_eq!((0..4).fold(dir, |d, _| d.turn_right()), dir);
+            assert_eq!((0..4).fold(dir, |d, _| d.turn_left()), dir);
+            assert_eq!((0..8).fold(dir, |d, _| d.rotate_45()), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+    }
+
+    #[test]
+    fn diagonals() {
+        for dir in Direction::ALL {
+            let (dx, dy) = dir.as_step();
+            assert_eq!(dir.is_diagonal(), dx != 0 && dy != 0, "{dir:?}");
+        }
+        assert!(Direction::ALL_BASIC.iter().all(|dir| !dir.is_diagonal()));
+        assert!(Direction::ALL_DIAGONAL.iter().all(Direction::is_diagonal));
+    }
+
+    #[test]
+    fn chars_round_trip() {
+        for style in [CharStyle::Arrow, CharStyle::Letter] {
+            for dir in Direction::ALL {
+                if let Some(c) = dir.to_char(style) {
+                    assert_eq!(Direction::try_from(c), Ok(dir), "{c:?}");
+                }
+            }
+        }
+        assert!(Direction::ALL
+            .iter()
+            .all(|dir| dir.to_char(CharStyle::Arrow).is_some()));
+        assert_eq!(Direction::UpLeft.to_char(CharStyle::Letter), None);
+        assert_eq!(Direction::Left.to_char(CharStyle::Letter), Some('L'));
+    }
+
+    #[test]
+    fn rejects_other_chars() {
+        assert_eq!(Direction::try_from('x'), Err(ParseDirectionError('x')));
+        assert_eq!(Direction::try_from('u'), Err(ParseDirectionError('u')));
+        assert_eq!(
+            ParseDirectionError('x').to_string(),
+            "invalid direction character: 'x'"
+        );
+    }
+}
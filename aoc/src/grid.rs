@@ -0,0 +1,339 @@
+use crate::{check::Check, point::Point, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid of cells stored row by row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: isize,
+    height: isize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from row-major cells, panics if the cell count doesn't match the size
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "expected {width}x{height} cells, got {}",
+            cells.len()
+        );
+
+        Self {
+            cells,
+            width: width as isize,
+            height: height as isize,
+        }
+    }
+
+    /// Creates a grid where every cell is `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid from text, mapping every character to a cell
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = 0;
+        let mut height = 0;
+        for line in input.lines() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            if height == 0 {
+                width = cells.len();
+            }
+            assert_eq!(
+                cells.len() - before,
+                width,
+                "row {height} has a different width than the first row"
+            );
+            height += 1;
+        }
+
+        Self::new(width, height, cells)
+    }
+
+    /// Like [`Grid::parse`], but normalizes the input with `check` first, so ragged rows and
+    /// unexpected characters are errors with coordinates instead of panics or shifted cells
+    pub fn parse_checked(
+        input: &str,
+        check: Check,
+        f: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseError> {
+        let input = check.normalize(input)?;
+        Ok(Self::parse(&input, f))
+    }
+
+    pub fn width(&self) -> isize {
+        self.width
+    }
+
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    /// Returns true if the point is inside the grid
+    pub fn in_bounds(&self, point: impl Into<Point>) -> bool {
+        point.into().in_bounds(self.width, self.height)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        point
+            .in_bounds(self.width, self.height)
+            .then(|| (point.y * self.width + point.x) as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new(index as isize % self.width, index as isize / self.width)
+    }
+
+    /// Returns the cell at the point, or `None` if it is out of bounds
+    pub fn get(&self, point: impl Into<Point>) -> Option<&T> {
+        self.index_of(point.into()).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: impl Into<Point>) -> Option<&mut T> {
+        self.index_of(point.into()).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the cell at the point, returning the previous value if it was in bounds
+    pub fn set(&mut self, point: impl Into<Point>, value: T) -> Option<T> {
+        self.get_mut(point)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Swaps two cells, panics if either is out of bounds
+    pub fn swap(&mut self, a: impl Into<Point>, b: impl Into<Point>) {
+        let (a, b) = (a.into(), b.into());
+        let a = self
+            .index_of(a)
+            .unwrap_or_else(|| panic!("{a} is out of bounds"));
+        let b = self
+            .index_of(b)
+            .unwrap_or_else(|| panic!("{b} is out of bounds"));
+        self.cells.swap(a, b);
+    }
+
+    /// Returns the row at `y` as a slice
+    pub fn row(&self, y: isize) -> Option<&[T]> {
+        (0..self.height).contains(&y).then(|| {
+            let start = (y * self.width) as usize;
+            &self.cells[start..start + self.width as usize]
+        })
+    }
+
+    /// Iterates over the rows from top to bottom, a 0-width grid still has `height` empty rows
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y).unwrap())
+    }
+
+    /// Returns the cells of column `x` from top to bottom
+    pub fn column(&self, x: isize) -> impl Iterator<Item = &T> {
+        let width = self.width as usize;
+        let cells = if (0..self.width).contains(&x) {
+            &self.cells[x as usize..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over every point in row-major order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Iterates over every cell together with its position in row-major order
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i as isize % width, i as isize / width), cell))
+    }
+
+    /// Iterates over the cells in row-major order without their positions
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Returns the first position (in row-major order) whose cell matches the predicate
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_of(i))
+    }
+
+    /// Returns every position holding `value`
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.cells()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// Returns the in-bounds neighbors of a point, including diagonals if specified
+    pub fn neighbors(
+        &self,
+        point: impl Into<Point>,
+        include_diagonals: bool,
+    ) -> impl Iterator<Item = Point> + '_ {
+        point
+            .into()
+            .neighbors(include_diagonals)
+            .into_iter()
+            .filter(|p| self.in_bounds(*p))
+    }
+
+    /// Creates a new grid of the same size by mapping every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: P) -> &T {
+        let point = point.into();
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is out of bounds"))
+    }
+}
+
+impl<T, P: Into<Point>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, point: P) -> &mut T {
+        let point = point.into();
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is out of bounds"))
+    }
+}
+
+// Renders one row per line with no separators, so a `Grid<char>` prints back as its input
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn parse_reads_rows_top_to_bottom() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 0)], 'a');
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(Grid::parse("", |c| c).height(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has a different width than the first row")]
+    fn parse_panics_on_ragged_rows() {
+        Grid::parse("abc\nde\n", |c| c);
+    }
+
+    #[test]
+    fn out_of_bounds_access_is_none() {
+        let mut grid = sample();
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.set(Point::new(0, 2), 'x'), None);
+        assert_eq!(grid.set(Point::new(1, 1), 'x'), Some('e'));
+        assert_eq!(grid[Point::new(1, 1)], 'x');
+        assert!(!grid.in_bounds(Point::new(-1, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is out of bounds")]
+    fn index_panics_out_of_bounds() {
+        let _ = sample()[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.row(-1), None);
+        assert_eq!(grid.rows().count(), 2);
+
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.column(-1).count(), 0);
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn zero_width_grids_have_empty_rows_and_no_columns() {
+        let grid: Grid<char> = Grid::new(0, 3, Vec::new());
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [&[] as &[char]; 3]);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.points().count(), 0);
+    }
+
+    #[test]
+    fn find_and_positions_of_go_in_row_major_order() {
+        let grid = Grid::parse("#.#\n.##\n", |c| c);
+        assert_eq!(grid.find(|&c| c == '.'), Some(Point::new(1, 0)));
+        assert_eq!(grid.find(|&c| c == 'x'), None);
+        let walls: Vec<_> = grid.positions_of(&'#').collect();
+        assert_eq!(
+            walls,
+            [(0, 0), (2, 0), (1, 1), (2, 1)].map(|(x, y)| Point::new(x, y))
+        );
+    }
+
+    #[test]
+    fn map_keeps_the_shape() {
+        let grid = Grid::parse("12\n34\n", |c| c.to_digit(10).unwrap());
+        let doubled = grid.map(|d| d * 2);
+        assert_eq!((doubled.width(), doubled.height()), (2, 2));
+        assert_eq!(doubled.values().copied().collect::<Vec<_>>(), [2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn display_prints_the_input_back() {
+        let input = "#..#\n.##.\n";
+        assert_eq!(Grid::parse(input, |c| c).to_string(), input);
+    }
+}
@@ -0,0 +1,437 @@
+//! Hexagonal grids in axial coordinates.
+//!
+//! A `Hex` stores the axial `q` (column-like) and `r` (row-like) coordinates, the third cube
+//! coordinate is always `s = -q - r`. Direction names follow the pointy-top orientation, for
+//! flat-top maps use `HexDirection::from_name` with `Orientation::FlatTop` to parse the
+//! compass names used there.
+
+use fxhash::FxHashMap as HashMap;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// Whether the hexagons have a pointed or a flat top
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    PointyTop,
+    FlatTop,
+}
+
+/// How a hex map is stored in a rectangular grid of `(column, row)` offset coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OffsetLayout {
+    /// Pointy-top, odd rows are shoved right
+    OddR,
+    /// Pointy-top, even rows are shoved right
+    EvenR,
+    /// Flat-top, odd columns are shoved down
+    OddQ,
+    /// Flat-top, even columns are shoved down
+    EvenQ,
+}
+
+impl OffsetLayout {
+    pub fn orientation(&self) -> Orientation {
+        match self {
+            OffsetLayout::OddR | OffsetLayout::EvenR => Orientation::PointyTop,
+            OffsetLayout::OddQ | OffsetLayout::EvenQ => Orientation::FlatTop,
+        }
+    }
+}
+
+/// The six neighbors of a hexagon, named for the pointy-top orientation
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection {
+    /// All six directions in counter-clockwise order, starting with `East`
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// The axial `(dq, dr)` step of the direction
+    pub fn as_step(&self) -> (isize, isize) {
+        match self {
+            HexDirection::East => (1, 0),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::West => (-1, 0),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::SouthEast => (0, 1),
+        }
+    }
+
+    /// Rotates 60° counter-clockwise
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 6]
+    }
+
+    /// Rotates 60° clockwise
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 5) % 6]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 6]
+    }
+
+    /// Parses the lowercase compass name used by puzzles in the given orientation, pointy-top
+    /// maps use `e`, `ne`, `nw`, `w`, `sw`, `se` and flat-top maps `n`, `ne`, `nw`, `s`, `sw`, `se`
+    pub fn from_name(name: &str, orientation: Orientation) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.name(orientation) == name)
+    }
+
+    /// The lowercase compass name in the given orientation
+    pub fn name(&self, orientation: Orientation) -> &'static str {
+        match (orientation, self) {
+            (Orientation::PointyTop, HexDirection::East) => "e",
+            (Orientation::PointyTop, HexDirection::NorthEast) => "ne",
+            (Orientation::PointyTop, HexDirection::NorthWest) => "nw",
+            (Orientation::PointyTop, HexDirection::West) => "w",
+            (Orientation::PointyTop, HexDirection::SouthWest) => "sw",
+            (Orientation::PointyTop, HexDirection::SouthEast) => "se",
+            (Orientation::FlatTop, HexDirection::East) => "se",
+            (Orientation::FlatTop, HexDirection::NorthEast) => "ne",
+            (Orientation::FlatTop, HexDirection::NorthWest) => "n",
+            (Orientation::FlatTop, HexDirection::West) => "nw",
+            (Orientation::FlatTop, HexDirection::SouthWest) => "sw",
+            (Orientation::FlatTop, HexDirection::SouthEast) => "s",
+        }
+    }
+}
+
+impl Hex {
+    pub fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a Hex from cube coordinates, panics if they don't sum to zero
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Self {
+        assert_eq!(q + r + s, 0, "cube coordinates must sum to zero");
+        Self::new(q, r)
+    }
+
+    /// The third cube coordinate
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    /// Converts the Hex to cube coordinates (q, r, s)
+    pub fn as_cube(&self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Returns a new Hex moved one step in the given direction
+    pub fn step(&self, direction: HexDirection) -> Self {
+        let (dq, dr) = direction.as_step();
+        Self::new(self.q + dq, self.r + dr)
+    }
+
+    /// Returns all six adjacent hexagons in the order of `HexDirection::ALL`
+    pub fn neighbors(&self) -> [Hex; 6] {
+        HexDirection::ALL.map(|dir| self.step(dir))
+    }
+
+    /// Returns the number of steps to another hexagon
+    pub fn distance(&self, other: &Hex) -> usize {
+        let diff = *self - *other;
+        diff.q
+            .unsigned_abs()
+            .max(diff.r.unsigned_abs())
+            .max(diff.s().unsigned_abs())
+    }
+
+    /// Returns the hexagons exactly `radius` steps away, starting at the south-west corner and
+    /// walking counter-clockwise
+    pub fn ring(&self, radius: usize) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        let mut ring = Vec::with_capacity(6 * radius);
+        let mut hex = *self + HexDirection::SouthWest * radius as isize;
+        for dir in HexDirection::ALL {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += dir;
+            }
+        }
+        ring
+    }
+
+    /// Returns every hexagon within `radius` steps, ring by ring starting at the center
+    pub fn spiral(&self, radius: usize) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// Converts `(column, row)` offset coordinates in the given layout
+    pub fn from_offset(col: isize, row: isize, layout: OffsetLayout) -> Self {
+        match layout {
+            OffsetLayout::OddR => Self::new(col - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenR => Self::new(col - (row + (row & 1)) / 2, row),
+            OffsetLayout::OddQ => Self::new(col, row - (col - (col & 1)) / 2),
+            OffsetLayout::EvenQ => Self::new(col, row - (col + (col & 1)) / 2),
+        }
+    }
+
+    /// Converts to `(column, row)` offset coordinates in the given layout
+    pub fn to_offset(&self, layout: OffsetLayout) -> (isize, isize) {
+        let (q, r) = (self.q, self.r);
+        match layout {
+            OffsetLayout::OddR => (q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenR => (q + (r + (r & 1)) / 2, r),
+            OffsetLayout::OddQ => (q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenQ => (q, r + (q + (q & 1)) / 2),
+        }
+    }
+}
+
+/// Parses a hex map stored in offset layout, one row per line.
+///
+/// Whitespace is skipped, so both compact rows and staggered drawings like the ones produced by
+/// `render` work, but it also means cells can't be whitespace.
+pub fn parse<T>(
+    input: &str,
+    layout: OffsetLayout,
+    mut f: impl FnMut(char) -> T,
+) -> HashMap<Hex, T> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .filter(|c| !c.is_whitespace())
+                .enumerate()
+                .map(move |(col, c)| (col as isize, row as isize, c))
+        })
+        .map(|(col, row, c)| (Hex::from_offset(col, row, layout), f(c)))
+        .collect()
+}
+
+/// Renders a hex map in offset layout, with `f` receiving `None` for holes in the map.
+///
+/// Row layouts are drawn staggered with a space between cells and shoved rows indented by one,
+/// column layouts are drawn compact with one character per cell. The drawing starts on an even
+/// row, or an even column for column layouts, so [`parse`] reads it back as the same map, only
+/// shifted to start near `(0, 0)`.
+pub fn render<T>(
+    map: &HashMap<Hex, T>,
+    layout: OffsetLayout,
+    mut f: impl FnMut(Option<&T>) -> char,
+) -> String {
+    let offsets: Vec<(isize, isize)> = map.keys().map(|hex| hex.to_offset(layout)).collect();
+    let (Some(min_col), Some(max_col), Some(min_row), Some(max_row)) = (
+        offsets.iter().map(|o| o.0).min(),
+        offsets.iter().map(|o| o.0).max(),
+        offsets.iter().map(|o| o.1).min(),
+        offsets.iter().map(|o| o.1).max(),
+    ) else {
+        return String::new();
+    };
+    // Starting on an odd row or column would flip which ones are shoved once parsed
+    let (min_col, min_row) = match layout {
+        OffsetLayout::OddR | OffsetLayout::EvenR => (min_col, min_row - (min_row & 1)),
+        OffsetLayout::OddQ | OffsetLayout::EvenQ => (min_col - (min_col & 1), min_row),
+    };
+
+    let mut out = String::new();
+    for row in min_row..=max_row {
+        let staggered = matches!(layout, OffsetLayout::OddR | OffsetLayout::EvenR);
+        let shoved = match layout {
+            OffsetLayout::OddR => row & 1 == 1,
+            OffsetLayout::EvenR => row & 1 == 0,
+            _ => false,
+        };
+        if shoved {
+            out.push(' ');
+        }
+
+        for col in min_col..=max_col {
+            if staggered && col > min_col {
+                out.push(' ');
+            }
+            out.push(f(map.get(&Hex::from_offset(col, row, layout))));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+impl Add<Hex> for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub<Hex> for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Add<HexDirection> for Hex {
+    type Output = Hex;
+
+    fn add(self, direction: HexDirection) -> Hex {
+        self.step(direction)
+    }
+}
+
+impl AddAssign<HexDirection> for Hex {
+    fn add_assign(&mut self, direction: HexDirection) {
+        *self = self.step(direction);
+    }
+}
+
+impl AddAssign<Hex> for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Hex> for Hex {
+    fn sub_assign(&mut self, other: Hex) {
+        *self = *self - other;
+    }
+}
+
+// Scaling a direction gives the offset of walking that many steps
+impl Mul<isize> for HexDirection {
+    type Output = Hex;
+
+    fn mul(self, steps: isize) -> Hex {
+        let (dq, dr) = self.as_step();
+        Hex::new(dq * steps, dr * steps)
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [OffsetLayout; 4] = [
+        OffsetLayout::OddR,
+        OffsetLayout::EvenR,
+        OffsetLayout::OddQ,
+        OffsetLayout::EvenQ,
+    ];
+
+    #[test]
+    fn offset_conversions_round_trip() {
+        for layout in LAYOUTS {
+            for row in -3..=3 {
+                for col in -3..=3 {
+                    let hex = Hex::from_offset(col, row, layout);
+                    assert_eq!(hex.to_offset(layout), (col, row), "{layout:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn offset_conversions_shove_the_right_rows() {
+        // In odd-r the odd row 1 is shoved right, so its column 0 sits south-east of (0, 0)
+        assert_eq!(
+            Hex::from_offset(0, 1, OffsetLayout::OddR),
+            Hex::new(0, 0) + HexDirection::SouthEast
+        );
+        assert_eq!(
+            Hex::from_offset(0, 1, OffsetLayout::EvenR),
+            Hex::new(0, 0) + HexDirection::SouthWest
+        );
+        assert_eq!(Hex::from_offset(1, 0, OffsetLayout::OddQ), Hex::new(1, 0));
+        assert_eq!(Hex::from_offset(1, 0, OffsetLayout::EvenQ), Hex::new(1, -1));
+        assert_eq!(Hex::new(2, -1).as_cube(), (2, -1, -1));
+        assert_eq!(Hex::from_cube(2, -1, -1), Hex::new(2, -1));
+    }
+
+    #[test]
+    fn distance_counts_steps() {
+        let origin = Hex::default();
+        assert_eq!(origin.distance(&origin), 0);
+        for neighbor in origin.neighbors() {
+            assert_eq!(origin.distance(&neighbor), 1);
+        }
+        assert_eq!(origin.distance(&Hex::new(3, -1)), 3);
+        assert_eq!(Hex::new(-2, 3).distance(&Hex::new(2, -1)), 4);
+    }
+
+    #[test]
+    fn rings_and_spirals_cover_each_distance_once() {
+        let center = Hex::new(2, -1);
+        assert_eq!(center.ring(0), [center]);
+        for radius in 1..=3 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius);
+            assert!(ring.iter().all(|hex| center.distance(hex) == radius));
+            // Walking the ring only ever takes single steps
+            for pair in ring.windows(2) {
+                assert_eq!(pair[0].distance(&pair[1]), 1);
+            }
+        }
+
+        let spiral = center.spiral(2);
+        assert_eq!(spiral.len(), 1 + 6 + 12);
+        assert_eq!(spiral[0], center);
+        let unique: std::collections::HashSet<_> = spiral.iter().collect();
+        assert_eq!(unique.len(), spiral.len());
+    }
+
+    #[test]
+    fn render_and_parse_round_trip_from_an_odd_row() {
+        for layout in LAYOUTS {
+            // Row 3 and column 3 are odd in every layout
+            let a = Hex::from_offset(3, 3, layout);
+            let map: HashMap<Hex, char> = [
+                (a, 'a'),
+                (a + HexDirection::East, 'b'),
+                (a + HexDirection::SouthEast, 'c'),
+            ]
+            .into_iter()
+            .collect();
+
+            let drawing = render(&map, layout, |c| c.copied().unwrap_or('.'));
+            let parsed: HashMap<Hex, char> = parse(&drawing, layout, |c| c)
+                .into_iter()
+                .filter(|&(_, c)| c != '.')
+                .collect();
+
+            let find = |c| *parsed.iter().find(|&(_, &v)| v == c).unwrap().0;
+            let (pa, pb, pc) = (find('a'), find('b'), find('c'));
+            assert_eq!(pa.distance(&pb), 1, "{layout:?}\n{drawing}");
+            assert_eq!(pb - pa, Hex::new(1, 0), "{layout:?}\n{drawing}");
+            assert_eq!(pc - pa, Hex::new(0, 1), "{layout:?}\n{drawing}");
+        }
+    }
+}
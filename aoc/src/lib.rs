@@ -1,5 +1,28 @@
+pub mod check;
 mod direction;
+mod grid;
+pub mod hex;
+pub mod linalg;
+pub mod math;
+pub mod parse;
 mod point;
+mod point3;
+mod point_n;
+mod range_set;
+mod region;
+pub mod search;
+mod solution;
+mod union_find;
 
-pub use direction::Direction;
-pub use point::Point;
+pub use direction::{CharStyle, Direction, ParseDirectionError};
+// The maps `search::reachable` and `hex::parse` return, so callers don't need fxhash to name them
+pub use fxhash::{FxHashMap, FxHashSet};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use point::{Point, PointParseError};
+pub use point3::Point3;
+pub use point_n::PointN;
+pub use range_set::RangeSet;
+pub use region::{Connectivity, Region, Regions};
+pub use solution::{Day, Solution, Variant};
+pub use union_find::{HashUnionFind, UnionFind};
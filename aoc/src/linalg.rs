@@ -0,0 +1,248 @@
+//! Exact solving of integer linear systems, no floating point involved.
+
+use crate::math::gcd;
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// An exact rational number, always stored in lowest terms with a positive denominator
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Fraction {
+    /// Creates a reduced fraction, panics if the denominator is zero
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "denominator must not be zero");
+        let sign = denominator.signum();
+        let divisor = gcd(numerator, denominator).max(1);
+        Self {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Returns the value if the fraction is a whole number
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+}
+
+impl From<i128> for Fraction {
+    fn from(n: i128) -> Self {
+        Self::new(n, 1)
+    }
+}
+
+impl Add for Fraction {
+    type Output = Fraction;
+
+    fn add(self, other: Fraction) -> Fraction {
+        Fraction::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Fraction;
+
+    fn sub(self, other: Fraction) -> Fraction {
+        self + -other
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Fraction;
+
+    fn mul(self, other: Fraction) -> Fraction {
+        Fraction::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Div for Fraction {
+    type Output = Fraction;
+
+    fn div(self, other: Fraction) -> Fraction {
+        Fraction::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        Fraction {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// The outcome of solving `A x = b`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Solutions {
+    /// Exactly one solution, one value per unknown
+    Unique(Vec<Fraction>),
+    /// The equations contradict each other
+    Inconsistent,
+    /// The equations are consistent but don't pin down every unknown
+    Infinite,
+}
+
+/// Solves the square system `A x = b` exactly with fraction-free (Bareiss) elimination.
+///
+/// `a` holds one row per equation, panics if it isn't square or doesn't match `b`.
+pub fn solve(a: &[Vec<i128>], b: &[i128]) -> Solutions {
+    let n = a.len();
+    assert_eq!(b.len(), n, "expected one right-hand side per equation");
+    assert!(
+        a.iter().all(|row| row.len() == n),
+        "expected a square matrix"
+    );
+
+    // Augmented matrix [A | b]
+    let mut m: Vec<Vec<i128>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &rhs)| row.iter().copied().chain([rhs]).collect())
+        .collect();
+
+    let mut pivot_columns = Vec::new();
+    let mut previous_pivot = 1;
+    for column in 0..=n {
+        let rank = pivot_columns.len();
+        let Some(pivot_row) = (rank..n).find(|&row| m[row][column] != 0) else {
+            continue;
+        };
+        m.swap(rank, pivot_row);
+
+        let (top, bottom) = m.split_at_mut(rank + 1);
+        let pivot_row = &top[rank];
+        let pivot = pivot_row[column];
+        for row in bottom {
+            let factor = row[column];
+            for (value, &above) in row.iter_mut().zip(pivot_row).skip(column) {
+                // Exact division, every entry is a minor of the original matrix
+                *value = (pivot * *value - factor * above) / previous_pivot;
+            }
+        }
+        previous_pivot = pivot;
+        pivot_columns.push(column);
+    }
+
+    if pivot_columns.last() == Some(&n) {
+        return Solutions::Inconsistent;
+    }
+    if pivot_columns.len() < n {
+        return Solutions::Infinite;
+    }
+
+    // Full rank, so the matrix is upper triangular with pivots on the diagonal
+    let mut x = vec![Fraction::from(0); n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(Fraction::from(m[row][n]), |acc, c| {
+            acc - Fraction::from(m[row][c]) * x[c]
+        });
+        x[row] = sum / Fraction::from(m[row][row]);
+    }
+    Solutions::Unique(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique(values: &[(i128, i128)]) -> Solutions {
+        Solutions::Unique(values.iter().map(|&(n, d)| Fraction::new(n, d)).collect())
+    }
+
+    #[test]
+    fn fractions_stay_reduced() {
+        assert_eq!(Fraction::new(4, -6), Fraction::new(-2, 3));
+        assert_eq!(Fraction::new(4, -6).denominator(), 3);
+        assert_eq!(
+            Fraction::new(1, 2) + Fraction::new(1, 3),
+            Fraction::new(5, 6)
+        );
+        assert_eq!(Fraction::new(6, 3).to_integer(), Some(2));
+        assert_eq!(Fraction::new(1, 3).to_integer(), None);
+        assert!(Fraction::new(1, 3) < Fraction::new(1, 2));
+        assert_eq!(Fraction::new(-3, 4).to_string(), "-3/4");
+    }
+
+    #[test]
+    fn solves_a_unique_system() {
+        // The first machine of day 13's example: 80 presses of A and 40 of B
+        let a = [vec![94, 22], vec![34, 67]];
+        assert_eq!(solve(&a, &[8400, 5400]), unique(&[(80, 1), (40, 1)]));
+        // And one with a fractional solution
+        let a = [vec![2, 0], vec![0, 3]];
+        assert_eq!(solve(&a, &[1, 1]), unique(&[(1, 2), (1, 3)]));
+    }
+
+    #[test]
+    fn solves_three_unknowns_needing_a_row_swap() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27, the first pivot column starts with 0
+        let a = [vec![0, 2, 5], vec![1, 1, 1], vec![2, 5, -1]];
+        assert_eq!(solve(&a, &[-4, 6, 27]), unique(&[(5, 1), (3, 1), (-2, 1)]));
+    }
+
+    #[test]
+    fn singular_systems_are_infinite_or_inconsistent() {
+        // Both equations describe the same line
+        let a = [vec![1, 2], vec![2, 4]];
+        assert_eq!(solve(&a, &[3, 6]), Solutions::Infinite);
+        // Parallel lines never meet
+        assert_eq!(solve(&a, &[3, 7]), Solutions::Inconsistent);
+        // Nothing constrains anything
+        let a = [vec![0, 0], vec![0, 0]];
+        assert_eq!(solve(&a, &[0, 0]), Solutions::Infinite);
+        assert_eq!(solve(&a, &[0, 1]), Solutions::Inconsistent);
+    }
+}
@@ -0,0 +1,330 @@
+//! Number theory helpers that work for every primitive integer type.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The primitive integer operations the helpers in this module are built on
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute value, the identity for unsigned types
+    fn abs(self) -> Self;
+
+    /// The least non-negative remainder of `self` divided by `m`
+    fn rem_euclid(self, m: Self) -> Self;
+
+    /// Computes `self * other mod m` without overflowing for any `0 <= self, other < m`
+    fn mul_mod(self, other: Self, m: Self) -> Self;
+}
+
+/// Integers with a sign, needed for Bézout coefficients
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($abs:expr; $($t:ty => $wide:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    $abs(self)
+                }
+
+                fn rem_euclid(self, m: Self) -> Self {
+                    <$t>::rem_euclid(self, m)
+                }
+
+                fn mul_mod(self, other: Self, m: Self) -> Self {
+                    (self as $wide * other as $wide).rem_euclid(m as $wide) as $t
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_wide_integer {
+    ($abs:expr; $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    $abs(self)
+                }
+
+                fn rem_euclid(self, m: Self) -> Self {
+                    <$t>::rem_euclid(self, m)
+                }
+
+                fn mul_mod(self, other: Self, m: Self) -> Self {
+                    if let Some(product) = self.checked_mul(other) {
+                        return product.rem_euclid(m);
+                    }
+
+                    // No wider type to fall back on, so multiply by doubling and adding
+                    let add_mod = |a: Self, b: Self| if a >= m - b { a - (m - b) } else { a + b };
+                    let (mut a, mut b) = (self.rem_euclid(m), other.rem_euclid(m));
+                    let mut result = 0;
+                    while b > 0 {
+                        if b % 2 == 1 {
+                            result = add_mod(result, a);
+                        }
+                        a = add_mod(a, a);
+                        b /= 2;
+                    }
+                    result
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(|n: Self| n.abs(); i8 => i128, i16 => i128, i32 => i128, i64 => i128, isize => i128);
+impl_integer!(|n| n; u8 => u128, u16 => u128, u32 => u128, u64 => u128, usize => u128);
+impl_wide_integer!(|n: Self| n.abs(); i128);
+impl_wide_integer!(|n| n; u128);
+
+impl SignedInteger for i8 {}
+impl SignedInteger for i16 {}
+impl SignedInteger for i32 {}
+impl SignedInteger for i64 {}
+impl SignedInteger for i128 {}
+impl SignedInteger for isize {}
+
+/// Greatest common divisor, always non-negative and `gcd(0, 0) == 0`
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative and zero if either argument is zero
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Greatest common divisor of all the numbers, zero for an empty iterator
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// Least common multiple of all the numbers, one for an empty iterator
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// Extended Euclidean algorithm, returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`
+pub fn ext_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns `x` in `0..m` such that `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime and `m`
+/// isn't 0
+pub fn mod_inv<T: SignedInteger>(a: T, m: T) -> Option<T> {
+    if m == T::ZERO {
+        return None;
+    }
+    let m = m.abs();
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// Computes `base ^ exp mod m` by repeated squaring, `exp` must be non-negative
+///
+/// # Panics
+///
+/// If `m` is 0, there's no remainder to reduce to.
+pub fn mod_pow<T: Integer>(base: T, mut exp: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut base = base.rem_euclid(m);
+    let mut result = T::ONE.rem_euclid(m);
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = result.mul_mod(base, m);
+        }
+        base = base.mul_mod(base, m);
+        exp = exp / two;
+    }
+    result
+}
+
+/// Chinese remainder theorem for `(residue, modulus)` pairs, the moduli don't need to be coprime.
+///
+/// Returns `(x, l)` where `x` is the smallest non-negative solution and `l` the lcm of the
+/// moduli, so every solution is `x + k * l`. Returns `None` if the congruences contradict or a
+/// modulus is 0.
+pub fn crt<T: SignedInteger>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(a1, m1), (a2, m2)| {
+            if m2 == T::ZERO {
+                return None;
+            }
+            let m2 = m2.abs();
+            let a2 = a2.rem_euclid(m2);
+            let g = gcd(m1, m2);
+            let diff = (a2 - a1).rem_euclid(m2);
+            if diff % g != T::ZERO {
+                return None;
+            }
+
+            // Solve m1 * k ≡ a2 - a1 (mod m2) for k, reduced by the common factor
+            let reduced = m2 / g;
+            let inverse = mod_inv(m1 / g, reduced)?;
+            let k = (diff / g).mul_mod(inverse, reduced);
+            let l = m1 / g * m2;
+            Some(((a1 + m1 * k).rem_euclid(l), l))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RANGE: std::ops::RangeInclusive<i64> = -30..=30;
+
+    fn brute_gcd(a: i64, b: i64) -> i64 {
+        (1..=a.abs().max(b.abs()))
+            .rev()
+            .find(|d| a % d == 0 && b % d == 0)
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn gcd_and_lcm_match_brute_force() {
+        for a in RANGE {
+            for b in RANGE {
+                assert_eq!(gcd(a, b), brute_gcd(a, b), "gcd({a}, {b})");
+
+                let expected = if a == 0 || b == 0 {
+                    0
+                } else {
+                    (1..=a.abs() * b.abs())
+                        .find(|m| m % a == 0 && m % b == 0)
+                        .unwrap()
+                };
+                assert_eq!(lcm(a, b), expected, "lcm({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn gcd_and_lcm_over_iterators() {
+        assert_eq!(gcd_all([12u32, 18, 30]), 6);
+        assert_eq!(gcd_all(Vec::<u32>::new()), 0);
+        assert_eq!(lcm_all([4u64, 6, 10]), 60);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+        assert_eq!(
+            lcm_all((1..=20u128).map(|n| n * 1_000_000)),
+            232_792_560_000_000
+        );
+    }
+
+    #[test]
+    fn ext_gcd_satisfies_bezout() {
+        for a in RANGE {
+            for b in RANGE {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(g, brute_gcd(a, b), "ext_gcd({a}, {b})");
+                assert_eq!(a * x + b * y, g, "ext_gcd({a}, {b}) = ({g}, {x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn mod_inv_matches_brute_force() {
+        for m in 1..=40i32 {
+            for a in -40..=40 {
+                let expected = (0..m).find(|x| (a * x).rem_euclid(m) == 1 % m);
+                assert_eq!(mod_inv(a, m), expected, "mod_inv({a}, {m})");
+            }
+        }
+        for a in -3..=3 {
+            assert_eq!(mod_inv(a, 0), None, "mod_inv({a}, 0)");
+        }
+    }
+
+    #[test]
+    fn mod_pow_matches_brute_force() {
+        for m in 1..=25u64 {
+            for base in 0..=25 {
+                let mut expected = 1 % m;
+                for exp in 0..=25 {
+                    assert_eq!(mod_pow(base, exp, m), expected, "{base}^{exp} mod {m}");
+                    expected = expected * base % m;
+                }
+            }
+        }
+        assert!(std::panic::catch_unwind(|| mod_pow(2u64, 3, 0)).is_err());
+
+        assert_eq!(mod_pow(-2i8, 7, 100), 72);
+        assert_eq!(mod_pow(3u128, 200, u128::MAX - 158), {
+            let m = u128::MAX - 158;
+            (0..200).fold(1, |acc: u128, _| acc.mul_mod(3, m))
+        });
+    }
+
+    #[test]
+    fn crt_matches_brute_force() {
+        for m1 in 1..=12i64 {
+            for m2 in 1..=12 {
+                for a1 in -3..m1 {
+                    for a2 in 0..m2 {
+                        let l = lcm(m1, m2);
+                        let expected = (0..l)
+                            .find(|x| x.rem_euclid(m1) == a1.rem_euclid(m1) && x % m2 == a2)
+                            .map(|x| (x, l));
+                        assert_eq!(
+                            crt([(a1, m1), (a2, m2)]),
+                            expected,
+                            "{a1} mod {m1}, {a2} mod {m2}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crt_with_several_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt(Vec::<(i32, i32)>::new()), Some((0, 1)));
+        assert_eq!(crt([(2, 3), (1, 0)]), None);
+        assert_eq!(crt([(0, 0)]), None);
+    }
+}
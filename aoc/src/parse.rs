@@ -0,0 +1,585 @@
+//! Helpers for the shapes puzzle inputs keep coming in. They work on [`Line`]s, which remember
+//! where they are in the input, so every error can say which line and column it's about.
+
+use std::{any::type_name, fmt, str::FromStr};
+
+/// Why a part of the input couldn't be parsed, and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number in the input
+    pub line: usize,
+    /// 1-based column, counted in chars
+    pub column: usize,
+    /// The text that couldn't be parsed, empty when something is missing at the end
+    pub snippet: String,
+    /// What should have been there, like "`|`" or "i32"
+    pub expected: String,
+    /// Why the snippet didn't do, when that isn't obvious, e.g. an overflowing number
+    pub reason: Option<String>,
+}
+
+impl ParseError {
+    /// An error about the `len` bytes at `offset` in the whole input, for parsers that don't
+    /// go through [`Line`]s
+    pub fn at_offset(input: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: input[offset..offset + len].to_string(),
+            expected: expected.into(),
+            reason: None,
+        }
+    }
+
+    pub fn with_reason(mut self, reason: impl fmt::Display) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    /// "expected ..., found ..." without the position
+    pub fn summary(&self) -> String {
+        let mut summary = match self.snippet.as_str() {
+            "" => format!("expected {}, found nothing", self.expected),
+            snippet => format!("expected {}, found `{snippet}`", self.expected),
+        };
+        if let Some(reason) = &self.reason {
+            summary.push_str(&format!(" ({reason})"));
+        }
+        summary
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.summary()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the input, or a part of one, that knows where it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number in the input
+    pub number: usize,
+    /// 1-based column `text` starts at
+    pub column: usize,
+    pub text: &'a str,
+}
+
+/// A text on its own, at the start of line 1
+impl<'a> From<&'a str> for Line<'a> {
+    fn from(text: &'a str) -> Self {
+        Line {
+            number: 1,
+            column: 1,
+            text,
+        }
+    }
+}
+
+impl<'a> From<&Line<'a>> for Line<'a> {
+    fn from(line: &Line<'a>) -> Self {
+        *line
+    }
+}
+
+/// The lines of the input, without their `\n` or `\r\n`
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        column: 1,
+        text,
+    })
+}
+
+/// Groups the lines into paragraphs separated by blank lines. Line endings don't matter, and
+/// leading, trailing and repeated blank lines are skipped.
+pub fn paragraphs(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut paragraphs = vec![Vec::new()];
+    for line in lines(input) {
+        if !line.text.trim().is_empty() {
+            paragraphs.last_mut().unwrap().push(line);
+        } else if !paragraphs.last().unwrap().is_empty() {
+            paragraphs.push(Vec::new());
+        }
+    }
+    paragraphs.retain(|paragraph| !paragraph.is_empty());
+    paragraphs
+}
+
+/// Like [`paragraphs`], for inputs made of exactly `N` of them, e.g. rules and updates
+pub fn paragraphs_exact<const N: usize>(input: &str) -> Result<[Vec<Line<'_>>; N], ParseError> {
+    let paragraphs = paragraphs(input);
+    let found = paragraphs.len();
+    paragraphs.try_into().map_err(|paragraphs: Vec<Vec<Line>>| {
+        let expected = format!("{N} paragraphs");
+        match paragraphs.get(N) {
+            Some(extra) => extra[0]
+                .error(0, extra[0].text.len(), expected)
+                .with_reason(format!("the input has {found}")),
+            None => ParseError::at_offset(input, input.len(), 0, expected)
+                .with_reason(format!("the input ends after {found}")),
+        }
+    })
+}
+
+impl<'a> Line<'a> {
+    /// An error about the text from byte `start` to `end`
+    pub fn error(&self, start: usize, end: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.column + self.text[..start].chars().count(),
+            snippet: self.text[start..end].to_string(),
+            expected: expected.into(),
+            reason: None,
+        }
+    }
+
+    /// The part of the text starting `start` bytes in, keeping its position
+    fn slice(&self, start: usize, end: usize) -> Line<'a> {
+        Line {
+            number: self.number,
+            column: self.column + self.text[..start].chars().count(),
+            text: &self.text[start..end],
+        }
+    }
+
+    /// Without leading and trailing whitespace
+    pub fn trim(&self) -> Line<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.slice(start, self.text.trim_end().len().max(start))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Line<'a>, ParseError> {
+        if !self.text.starts_with(prefix) {
+            // As much of the text as the prefix would have covered
+            let end = self
+                .text
+                .char_indices()
+                .nth(prefix.chars().count())
+                .map_or(self.text.len(), |(i, _)| i);
+            return Err(self.error(0, end, format!("`{prefix}`")));
+        }
+        Ok(self.slice(prefix.len(), self.text.len()))
+    }
+
+    /// Splits at the first `separator`, e.g. `47|53` or `190: 10 19`
+    pub fn split_once(&self, separator: &str) -> Result<(Line<'a>, Line<'a>), ParseError> {
+        let Some(i) = self.text.find(separator) else {
+            let end = self.text.len();
+            return Err(self.error(end, end, format!("`{separator}`")));
+        };
+        Ok((
+            self.slice(0, i),
+            self.slice(i + separator.len(), self.text.len()),
+        ))
+    }
+
+    /// Parses the whole text, ignoring surrounding whitespace
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError>
+    where
+        T::Err: fmt::Display,
+    {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        // `aoc::point::Point` reads better as `Point`
+        let name = type_name::<T>().rsplit("::").next().unwrap();
+        self.text[start..end]
+            .parse()
+            .map_err(|e| self.error(start, end, name).with_reason(e))
+    }
+
+    /// Every integer in the text, whatever is around them, so `p=0,4 v=3,-3` and
+    /// `Button A: X+94, Y+34` both work. A sign only counts when it isn't right after a digit,
+    /// which keeps ranges like `2-4` positive.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let mut start = i;
+            let signed = start > 0
+                && matches!(bytes[start - 1], b'-' | b'+')
+                && (start < 2 || !bytes[start - 2].is_ascii_digit());
+            if signed {
+                start -= 1;
+            }
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            ints.push(self.slice(start, i).parse()?);
+        }
+        Ok(ints)
+    }
+
+    /// Like [`Line::ints`], for lines with exactly `N` of them
+    pub fn ints_exact<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T::Err: fmt::Display,
+    {
+        let ints = self.ints()?;
+        let found = ints.len();
+        ints.try_into().map_err(|_| {
+            self.error(0, self.text.len(), format!("{N} numbers"))
+                .with_reason(format!("the line has {found}"))
+        })
+    }
+
+    /// A list of numbers separated by anything that can't be part of one, like `7 6 4 2`,
+    /// `75,47,61` or `3 | 5`. Unlike [`Line::ints`], everything between the separators has to
+    /// be a number.
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: fmt::Display,
+    {
+        let is_separator = |c: char| !(c.is_alphanumeric() || matches!(c, '-' | '+' | '.'));
+        let mut numbers = Vec::new();
+        let mut start = 0;
+        for (i, c) in self.text.char_indices().chain([(self.text.len(), ' ')]) {
+            if !is_separator(c) {
+                continue;
+            }
+            if start < i {
+                numbers.push(self.slice(start, i).parse()?);
+            }
+            start = i + c.len_utf8();
+        }
+        Ok(numbers)
+    }
+
+    /// `key=value` pairs separated by whitespace or commas, like `p=0,4 v=3,-3` or
+    /// `X=8400, Y=5400`. A value runs until the next key, so it may contain commas itself.
+    pub fn key_values(&self) -> Result<Vec<(&'a str, Line<'a>)>, ParseError> {
+        let is_key = |c: char| c.is_alphanumeric() || c == '_';
+        // Where each key starts and where its `=` is
+        let mut keys = Vec::new();
+        for (eq, _) in self.text.match_indices('=') {
+            let start = self.text[..eq]
+                .char_indices()
+                .rev()
+                .take_while(|&(_, c)| is_key(c))
+                .last()
+                .map_or(eq, |(i, _)| i);
+            if start == eq {
+                return Err(self.error(eq, eq + 1, "a key before `=`"));
+            }
+            keys.push((start, eq));
+        }
+
+        let first = keys.first().map_or(self.text.len(), |&(start, _)| start);
+        if keys.is_empty() || !self.text[..first].trim().is_empty() {
+            return Err(self.error(0, first, "`key=value`"));
+        }
+
+        keys.iter()
+            .enumerate()
+            .map(|(n, &(start, eq))| {
+                let end = keys.get(n + 1).map_or(self.text.len(), |&(next, _)| next);
+                let value = self.slice(eq + 1, end).trim();
+                let value = value.slice(0, value.text.trim_end_matches([',', ';']).len());
+                if value.text.is_empty() {
+                    let key = &self.text[start..eq];
+                    return Err(self.error(eq + 1, eq + 1, format!("a value for `{key}`")));
+                }
+                Ok((&self.text[start..eq], value))
+            })
+            .collect()
+    }
+}
+
+/// Matches a line against a template with `{}` holes and parses what's in them, with the types
+/// inferred from how the values are used. A template with a different number of holes than the
+/// bound tuple doesn't compile.
+///
+/// The first argument is a [`Line`], like the ones [`lines`] returns, so errors point at its
+/// place in the input. A `&str` works too, its errors are about line 1.
+///
+/// ```
+/// use aoc::{parse, scan, ParseError};
+///
+/// let line = parse::lines("p=0,4 v=3,-3").next().unwrap();
+/// let (px, py, vx, vy): (usize, usize, i32, i32) = scan!(line, "p={},{} v={},{}")?;
+/// assert_eq!((px, py, vx, vy), (0, 4, 3, -3));
+///
+/// let line = parse::lines("p=0,4 v=3,x").next().unwrap();
+/// let error = scan!(line, "p={},{} v={},{}").map(|(_, _, _, _): (u8, u8, i32, i32)| ());
+/// assert_eq!(
+///     error.unwrap_err().to_string(),
+///     "line 1, column 11: expected i32, found `x` (invalid digit found in string)"
+/// );
+///
+/// let (x, y): (i32, i32) = scan!("3,-4", "{},{}")?;
+/// assert_eq!((x, y), (3, -4));
+/// # Ok::<(), ParseError>(())
+/// ```
+///
+/// ```compile_fail
+/// # let line = aoc::parse::lines("1,2").next().unwrap();
+/// let (x, y, z): (u8, u8, u8) = aoc::scan!(line, "{},{}")?;
+/// # Ok::<(), aoc::ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $template:literal) => {{
+        const HOLES: usize = $crate::parse::holes($template);
+        $crate::parse::scan::<_, HOLES>($line, $template)
+    }};
+}
+
+/// Tuples of up to eight `FromStr` values, which [`scan!`](crate::scan) fills from the holes
+/// of its template in order
+pub trait FromScan: Sized {
+    const HOLES: usize;
+
+    fn from_holes(holes: &[Line]) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_scan {
+    ($holes:literal: $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromScan for ($($t,)+)
+        where
+            $($t::Err: fmt::Display),+
+        {
+            const HOLES: usize = $holes;
+
+            fn from_holes(holes: &[Line]) -> Result<Self, ParseError> {
+                Ok(($(holes[$i].parse::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_scan!(1: A 0);
+impl_from_scan!(2: A 0, B 1);
+impl_from_scan!(3: A 0, B 1, C 2);
+impl_from_scan!(4: A 0, B 1, C 2, D 3);
+impl_from_scan!(5: A 0, B 1, C 2, D 3, E 4);
+impl_from_scan!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_scan!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_scan!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// The number of `{}` in a [`scan!`](crate::scan) template, rejecting holes without text between
+/// them since there's no telling where one would end
+#[doc(hidden)]
+pub const fn holes(template: &str) -> usize {
+    let bytes = template.as_bytes();
+    let mut holes = 0;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            assert!(
+                i + 3 >= bytes.len() || bytes[i + 2] != b'{' || bytes[i + 3] != b'}',
+                "scan! needs text between two holes"
+            );
+            holes += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    holes
+}
+
+/// What [`scan!`](crate::scan) expands to. Every hole but the last ends at the first match of
+/// the text after it, and the last one runs to the end of the line.
+#[doc(hidden)]
+pub fn scan<'a, T: FromScan, const HOLES: usize>(
+    line: impl Into<Line<'a>>,
+    template: &str,
+) -> Result<T, ParseError> {
+    const {
+        assert!(
+            T::HOLES == HOLES,
+            "scan! binds a different number of values than its template has holes"
+        )
+    };
+
+    let mut pieces = template.split("{}");
+    let line = line.into();
+    let mut rest = line.strip_prefix(pieces.next().unwrap_or_default())?;
+    let mut holes = Vec::with_capacity(HOLES);
+    for literal in pieces {
+        let (hole, after) = match literal {
+            "" => (rest, rest.slice(rest.text.len(), rest.text.len())),
+            literal => rest.split_once(literal)?,
+        };
+        holes.push(hole);
+        rest = after;
+    }
+
+    if !rest.text.trim().is_empty() {
+        return Err(rest.error(0, rest.text.len(), "the end of the line"));
+    }
+    T::from_holes(&holes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        lines(text).next().unwrap()
+    }
+
+    #[test]
+    fn ints_keep_signs_but_not_range_dashes() {
+        assert_eq!(line("p=0,4 v=3,-3").ints::<i32>(), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(line("Button A: X+94, Y+34").ints::<u64>(), Ok(vec![94, 34]));
+        assert_eq!(line("2-4,6-8").ints::<u8>(), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(line("no numbers").ints::<u8>(), Ok(vec![]));
+        assert_eq!(line("Prize: X=8400, Y=5400").ints_exact(), Ok([8400, 5400]));
+        assert_eq!(line("1 2 3").ints_exact::<u8, 2>().unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn ints_report_where_a_number_doesnt_fit() {
+        let error = line("x=12, y=-3").ints::<u32>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        assert_eq!(error.snippet, "-3");
+        assert_eq!(error.expected, "u32");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 9: expected u32, found `-3` (invalid digit found in string)"
+        );
+    }
+
+    #[test]
+    fn numbers_accept_any_separator() {
+        assert_eq!(line("7 6  4 2").numbers::<i32>(), Ok(vec![7, 6, 4, 2]));
+        assert_eq!(line("75,47,61").numbers::<i32>(), Ok(vec![75, 47, 61]));
+        assert_eq!(line(" 3 | -5; 8 ").numbers::<i32>(), Ok(vec![3, -5, 8]));
+        assert_eq!(line("3   4").numbers::<i32>(), Ok(vec![3, 4]));
+    }
+
+    #[test]
+    fn numbers_reject_words() {
+        let error = lines("1 2\n3 four 5")
+            .nth(1)
+            .unwrap()
+            .numbers::<i32>()
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn key_values_allow_commas_in_values() {
+        let pairs = line("p=0,4 v=3,-3").key_values().unwrap();
+        let pairs: Vec<_> = pairs.iter().map(|(k, v)| (*k, v.text, v.column)).collect();
+        assert_eq!(pairs, [("p", "0,4", 3), ("v", "3,-3", 9)]);
+
+        let pairs = line("X=8400, Y=5400").key_values().unwrap();
+        let pairs: Vec<_> = pairs.iter().map(|(k, v)| (*k, v.text)).collect();
+        assert_eq!(pairs, [("X", "8400"), ("Y", "5400")]);
+    }
+
+    #[test]
+    fn key_values_need_keys_and_values() {
+        assert_eq!(line("Prize: X=1").key_values().unwrap_err().column, 1);
+        assert_eq!(line("a=1 =2").key_values().unwrap_err().column, 5);
+        assert_eq!(line("a=1, b=").key_values().unwrap_err().column, 8);
+    }
+
+    #[test]
+    fn paragraphs_ignore_line_endings_and_extra_blank_lines() {
+        let input = "\r\n1|2\r\n3|4\r\n\r\n \r\n5,6\r\n\r\n";
+        let paragraphs = paragraphs(input);
+        let texts: Vec<Vec<_>> = paragraphs
+            .iter()
+            .map(|p| p.iter().map(|l| (l.number, l.text)).collect())
+            .collect();
+        assert_eq!(texts, [vec![(2, "1|2"), (3, "3|4")], vec![(6, "5,6")]]);
+
+        assert!(paragraphs_exact::<2>(input).is_ok());
+        assert_eq!(paragraphs_exact::<3>(input).unwrap_err().line, 8);
+        assert_eq!(paragraphs_exact::<1>(input).unwrap_err().line, 6);
+    }
+
+    #[test]
+    fn split_and_strip_keep_columns() {
+        let (sum, numbers) = line("190: 10 19").split_once(":").unwrap();
+        assert_eq!(sum.parse::<u64>(), Ok(190));
+        assert_eq!(numbers.column, 5);
+        assert_eq!(
+            line("Prize: X=1").strip_prefix("Prize: ").unwrap().column,
+            8
+        );
+        assert_eq!(line("abc").split_once("|").unwrap_err().column, 4);
+        assert_eq!(line(" x ").parse::<u8>().unwrap_err().column, 2);
+    }
+
+    #[test]
+    fn errors_at_offsets_find_their_line() {
+        let input = "mul(1,2)\nxyz\n";
+        let error = ParseError::at_offset(input, 10, 2, "`mul(`");
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (2, 2, "yz")
+        );
+        let error = ParseError::at_offset(input, input.len(), 0, "a guard");
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.summary(), "expected a guard, found nothing");
+    }
+
+    #[test]
+    fn scan_fills_holes_in_order() {
+        let line = line("Button A: X+94, Y+34");
+        let (id, dx, dy): (char, u64, u64) = scan!(line, "Button {}: X+{}, Y+{}").unwrap();
+        assert_eq!((id, dx, dy), ('A', 94, 34));
+
+        // The last hole takes the rest of the line
+        let (id, rest): (char, String) = scan!(line, "Button {}: {}").unwrap();
+        assert_eq!((id, rest.as_str()), ('A', "X+94, Y+34"));
+    }
+
+    #[test]
+    fn scan_reports_mismatches() {
+        let robot = line("p=0,4 v=3,x");
+        let error = scan!(robot, "p={},{} v={},{}")
+            .map(|(_, _, _, _): (i32, i32, i32, i32)| ())
+            .unwrap_err();
+        assert_eq!((error.column, error.snippet.as_str()), (11, "x"));
+
+        let error = scan!(robot, "q={},{} v={},{}")
+            .map(|(_, _, _, _): (i32, i32, i32, i32)| ())
+            .unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (1, "`q=`"));
+
+        let error = scan!(robot, "p={},{} v")
+            .map(|(_, _): (i32, i32)| ())
+            .unwrap_err();
+        assert_eq!((error.column, error.snippet.as_str()), (8, "=3,x"));
+    }
+
+    #[test]
+    fn scan_takes_lines_or_text() {
+        let input = "a=1\nb=2";
+        let second = lines(input).nth(1).unwrap();
+        let error = scan!(&second, "a={}").map(|(_,): (u8,)| ()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let (b,): (u8,) = scan!("b=2", "b={}").unwrap();
+        assert_eq!(b, 2);
+        let text = String::from("b=x");
+        let error = scan!(text.as_str(), "b={}")
+            .map(|(_,): (u8,)| ())
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::direction::Direction;
 use std::{
+    fmt,
     num::ParseIntError,
     ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
@@ -63,12 +64,61 @@ impl Point {
     }
 }
 
+/// Why a [`Point`] couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointParseError {
+    /// Neither a `,` nor whitespace between the coordinates
+    MissingSeparator,
+    BadX(ParseIntError),
+    BadY(ParseIntError),
+}
+
+impl fmt::Display for PointParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointParseError::MissingSeparator => write!(f, "expected `x,y` or `x y`"),
+            PointParseError::BadX(e) => write!(f, "invalid x coordinate: {e}"),
+            PointParseError::BadY(e) => write!(f, "invalid y coordinate: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PointParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PointParseError::MissingSeparator => None,
+            PointParseError::BadX(e) | PointParseError::BadY(e) => Some(e),
+        }
+    }
+}
+
 impl FromStr for Point {
-    type Err = ParseIntError;
+    type Err = PointParseError;
 
+    /// Parses `x,y`, `(x, y)`, `x=1, y=2` or `x y`. Labels may be upper case, like `X=8400`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.trim().split_once(',').unwrap();
-        Ok(Self::new(x.parse()?, y.parse()?))
+        let s = s.trim();
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let (x, y) = s
+            .split_once(',')
+            .or_else(|| s.split_once(char::is_whitespace))
+            .ok_or(PointParseError::MissingSeparator)?;
+
+        // Only strips the label of the coordinate's own axis
+        let coordinate = |part: &str, label: char| {
+            let part = part.trim();
+            let unlabeled = [label, label.to_ascii_uppercase()]
+                .into_iter()
+                .find_map(|label| part.strip_prefix(label)?.trim_start().strip_prefix('='));
+            unlabeled.unwrap_or(part).trim().parse::<isize>()
+        };
+        Ok(Self::new(
+            coordinate(x, 'x').map_err(PointParseError::BadX)?,
+            coordinate(y, 'y').map_err(PointParseError::BadY)?,
+        ))
     }
 }
 
@@ -170,3 +220,47 @@ macro_rules! impl_numeric_ops {
 }
 
 impl_numeric_ops!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_common_forms() {
+        for s in [
+            "3,-4",
+            " 3, -4 ",
+            "(3, -4)",
+            "x=3, y=-4",
+            "X=3, Y=-4",
+            "x=3 y=-4",
+            "3 -4",
+        ] {
+            assert_eq!(s.parse(), Ok(Point::new(3, -4)), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn tells_which_part_is_wrong() {
+        assert_eq!(
+            "34".parse::<Point>(),
+            Err(PointParseError::MissingSeparator)
+        );
+        assert!(matches!(
+            "a,4".parse::<Point>(),
+            Err(PointParseError::BadX(_))
+        ));
+        assert!(matches!(
+            "3,".parse::<Point>(),
+            Err(PointParseError::BadY(_))
+        ));
+        assert!(matches!(
+            "y=3, x=4".parse::<Point>(),
+            Err(PointParseError::BadX(_))
+        ));
+        assert!(matches!(
+            "(3, 4".parse::<Point>(),
+            Err(PointParseError::BadX(_))
+        ));
+    }
+}
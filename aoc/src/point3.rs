@@ -0,0 +1,267 @@
+use std::{
+    num::ParseIntError,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a Point3 from a tuple of (x, y, z)
+    pub fn from_tuple(tuple: (isize, isize, isize)) -> Self {
+        Self::new(tuple.0, tuple.1, tuple.2)
+    }
+
+    /// Converts the Point3 to a tuple of (x, y, z)
+    pub fn as_tuple(&self) -> (isize, isize, isize) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Returns Manhattan distance to another point
+    pub fn manhattan_distance(&self, other: &Point3) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
+    }
+
+    /// Returns the points surrounding this one that differ in at most `max_changed` coordinates
+    fn neighbors_within(&self, max_changed: usize) -> Vec<Point3> {
+        let mut neighbors = Vec::new();
+        for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let changed = [dx, dy, dz].iter().filter(|&&d| d != 0).count();
+                    if (1..=max_changed).contains(&changed) {
+                        neighbors.push(*self + (dx, dy, dz));
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// Returns the 6 points sharing a face with this one
+    pub fn neighbors_6(&self) -> Vec<Point3> {
+        self.neighbors_within(1)
+    }
+
+    /// Returns the 18 points sharing a face or an edge with this one
+    pub fn neighbors_18(&self) -> Vec<Point3> {
+        self.neighbors_within(2)
+    }
+
+    /// Returns all 26 points touching this one, including corners
+    pub fn neighbors_26(&self) -> Vec<Point3> {
+        self.neighbors_within(3)
+    }
+
+    /// Returns true if the point is within the given bounds, starting at the origin
+    pub fn in_bounds(&self, width: isize, height: isize, depth: isize) -> bool {
+        self.in_box(
+            &Point3::new(0, 0, 0),
+            &Point3::new(width - 1, height - 1, depth - 1),
+        )
+    }
+
+    /// Returns true if the point is inside the box spanned by `min` and `max` (inclusive)
+    pub fn in_box(&self, min: &Point3, max: &Point3) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParseIntError;
+
+    /// Parses `x,y,z` or `(x, y, z)` as displayed, a missing coordinate fails like parsing an
+    /// empty string
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let mut parts = s.splitn(3, ',').map(str::trim);
+        let mut next = || parts.next().unwrap_or_default().parse();
+        Ok(Self::new(next()?, next()?, next()?))
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from(tuple: (isize, isize, isize)) -> Self {
+        Self::from_tuple(tuple)
+    }
+}
+
+impl From<(usize, usize, usize)> for Point3 {
+    fn from(tuple: (usize, usize, usize)) -> Self {
+        Self::new(tuple.0 as isize, tuple.1 as isize, tuple.2 as isize)
+    }
+}
+
+impl Add<Point3> for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign<Point3> for Point3 {
+    fn add_assign(&mut self, other: Point3) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl Sub<Point3> for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign<Point3> for Point3 {
+    fn sub_assign(&mut self, other: Point3) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl std::fmt::Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_numeric_ops {
+    ($($t:ty),*) => {
+        $(
+            impl Add<($t, $t, $t)> for Point3 {
+                type Output = Point3;
+
+                fn add(self, rhs: ($t, $t, $t)) -> Point3 {
+                    Point3::new(
+                        self.x + rhs.0 as isize,
+                        self.y + rhs.1 as isize,
+                        self.z + rhs.2 as isize,
+                    )
+                }
+            }
+
+            impl AddAssign<($t, $t, $t)> for Point3 {
+                fn add_assign(&mut self, rhs: ($t, $t, $t)) {
+                    self.x += rhs.0 as isize;
+                    self.y += rhs.1 as isize;
+                    self.z += rhs.2 as isize;
+                }
+            }
+
+            impl Sub<($t, $t, $t)> for Point3 {
+                type Output = Point3;
+
+                fn sub(self, rhs: ($t, $t, $t)) -> Point3 {
+                    Point3::new(
+                        self.x - rhs.0 as isize,
+                        self.y - rhs.1 as isize,
+                        self.z - rhs.2 as isize,
+                    )
+                }
+            }
+
+            impl SubAssign<($t, $t, $t)> for Point3 {
+                fn sub_assign(&mut self, rhs: ($t, $t, $t)) {
+                    self.x -= rhs.0 as isize;
+                    self.y -= rhs.1 as isize;
+                    self.z -= rhs.2 as isize;
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_ops!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbor_counts() {
+        let point = Point3::new(1, -2, 3);
+        for (neighbors, count) in [
+            (point.neighbors_6(), 6),
+            (point.neighbors_18(), 18),
+            (point.neighbors_26(), 26),
+        ] {
+            assert_eq!(neighbors.len(), count);
+            assert!(!neighbors.contains(&point));
+        }
+        assert!(point
+            .neighbors_6()
+            .iter()
+            .all(|n| n.manhattan_distance(&point) == 1));
+        assert!(point.neighbors_18().contains(&(point + (1, 1, 0))));
+        assert!(!point.neighbors_18().contains(&(point + (1, 1, 1))));
+    }
+
+    #[test]
+    fn parses_what_it_displays() {
+        let point = Point3::new(1, -2, 30);
+        assert_eq!("1,-2,30".parse(), Ok(point));
+        assert_eq!(" 1, -2, 30 ".parse(), Ok(point));
+        assert_eq!(point.to_string(), "(1, -2, 30)");
+        assert_eq!(point.to_string().parse(), Ok(point));
+    }
+
+    #[test]
+    fn rejects_malformed_points() {
+        for s in ["1,2", "1,2,3,4", "1,a,3", "", "(1,2,3"] {
+            assert!(s.parse::<Point3>().is_err(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let mut point = Point3::new(1, 2, 3);
+        assert_eq!(point + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+        assert_eq!(point - Point3::new(1, 1, 1), Point3::new(0, 1, 2));
+        assert_eq!(-point, Point3::new(-1, -2, -3));
+        point += (1u8, 0u8, 2u8);
+        point -= Point3::new(0, 2, 0);
+        assert_eq!(point, Point3::new(2, 0, 5));
+        assert_eq!(point.manhattan_distance(&Point3::new(0, 0, 0)), 7);
+    }
+
+    #[test]
+    fn bounds_are_inclusive_boxes() {
+        let (min, max) = (Point3::new(-1, 0, 2), Point3::new(1, 3, 2));
+        assert!(min.in_box(&min, &max) && max.in_box(&min, &max));
+        assert!(!Point3::new(2, 0, 2).in_box(&min, &max));
+        assert!(!Point3::new(0, 0, 1).in_box(&min, &max));
+
+        assert!(Point3::new(0, 0, 0).in_bounds(2, 3, 4));
+        assert!(Point3::new(1, 2, 3).in_bounds(2, 3, 4));
+        assert!(!Point3::new(2, 0, 0).in_bounds(2, 3, 4));
+        assert!(!Point3::new(0, 0, -1).in_bounds(2, 3, 4));
+    }
+}
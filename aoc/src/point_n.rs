@@ -0,0 +1,248 @@
+use crate::{point::Point, point3::Point3};
+use std::{
+    num::ParseIntError,
+    ops::{Add, AddAssign, Index, IndexMut, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A point with `D` integer coordinates, for puzzles beyond three dimensions
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct PointN<const D: usize>(pub [isize; D]);
+
+impl<const D: usize> PointN<D> {
+    pub fn new(coords: [isize; D]) -> Self {
+        Self(coords)
+    }
+
+    pub fn origin() -> Self {
+        Self([0; D])
+    }
+
+    pub fn coords(&self) -> &[isize; D] {
+        &self.0
+    }
+
+    /// Returns Manhattan distance to another point
+    pub fn manhattan_distance(&self, other: &PointN<D>) -> usize {
+        self.0
+            .iter()
+            .zip(other.0)
+            .map(|(a, b)| (a - b).unsigned_abs())
+            .sum()
+    }
+
+    /// Returns the points that differ in at most `max_changed` coordinates by one
+    fn neighbors_within(&self, max_changed: usize) -> Vec<PointN<D>> {
+        let mut neighbors = Vec::new();
+        let mut offset = [-1; D];
+        loop {
+            let changed = offset.iter().filter(|&&d| d != 0).count();
+            if (1..=max_changed).contains(&changed) {
+                neighbors.push(*self + PointN(offset));
+            }
+
+            // Count through every offset in {-1, 0, 1}^D like an odometer
+            let Some(i) = offset.iter().position(|&d| d < 1) else {
+                break;
+            };
+            offset[i] += 1;
+            offset[..i].fill(-1);
+        }
+        neighbors
+    }
+
+    /// Returns the `2 * D` points that differ by one in a single coordinate
+    pub fn orthogonal_neighbors(&self) -> Vec<PointN<D>> {
+        self.neighbors_within(1)
+    }
+
+    /// Returns all `3^D - 1` points touching this one, including every diagonal
+    pub fn neighbors(&self) -> Vec<PointN<D>> {
+        self.neighbors_within(D)
+    }
+
+    /// Returns true if every coordinate is within `0..size` of its dimension
+    pub fn in_bounds(&self, size: [isize; D]) -> bool {
+        self.0.iter().zip(size).all(|(&c, s)| (0..s).contains(&c))
+    }
+
+    /// Returns true if the point is inside the box spanned by `min` and `max` (inclusive)
+    pub fn in_box(&self, min: &PointN<D>, max: &PointN<D>) -> bool {
+        (0..D).all(|i| (min.0[i]..=max.0[i]).contains(&self.0[i]))
+    }
+}
+
+impl<const D: usize> Default for PointN<D> {
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+impl<const D: usize> FromStr for PointN<D> {
+    type Err = ParseIntError;
+
+    /// Parses `D` comma separated coordinates, optionally in parentheses as displayed. A missing
+    /// coordinate fails like parsing an empty string and extra coordinates fail to parse as the
+    /// last one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let mut parts = s.splitn(D, ',').map(str::trim);
+        let mut coords = [0; D];
+        for coord in coords.iter_mut() {
+            *coord = parts.next().unwrap_or_default().parse()?;
+        }
+        Ok(Self(coords))
+    }
+}
+
+impl From<Point> for PointN<2> {
+    fn from(point: Point) -> Self {
+        Self([point.x, point.y])
+    }
+}
+
+impl From<Point3> for PointN<3> {
+    fn from(point: Point3) -> Self {
+        Self([point.x, point.y, point.z])
+    }
+}
+
+impl<const D: usize> From<[isize; D]> for PointN<D> {
+    fn from(coords: [isize; D]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<const D: usize> Index<usize> for PointN<D> {
+    type Output = isize;
+
+    fn index(&self, i: usize) -> &isize {
+        &self.0[i]
+    }
+}
+
+impl<const D: usize> IndexMut<usize> for PointN<D> {
+    fn index_mut(&mut self, i: usize) -> &mut isize {
+        &mut self.0[i]
+    }
+}
+
+impl<const D: usize> Add<PointN<D>> for PointN<D> {
+    type Output = PointN<D>;
+
+    fn add(mut self, other: PointN<D>) -> PointN<D> {
+        self += other;
+        self
+    }
+}
+
+impl<const D: usize> AddAssign<PointN<D>> for PointN<D> {
+    fn add_assign(&mut self, other: PointN<D>) {
+        self.0.iter_mut().zip(other.0).for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<const D: usize> Sub<PointN<D>> for PointN<D> {
+    type Output = PointN<D>;
+
+    fn sub(mut self, other: PointN<D>) -> PointN<D> {
+        self -= other;
+        self
+    }
+}
+
+impl<const D: usize> SubAssign<PointN<D>> for PointN<D> {
+    fn sub_assign(&mut self, other: PointN<D>) {
+        self.0.iter_mut().zip(other.0).for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<const D: usize> Neg for PointN<D> {
+    type Output = PointN<D>;
+
+    fn neg(self) -> PointN<D> {
+        Self(self.0.map(|c| -c))
+    }
+}
+
+impl<const D: usize> std::fmt::Display for PointN<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbor_counts() {
+        let point = PointN([3, -1, 0, 7]);
+        let neighbors = point.neighbors();
+        assert_eq!(neighbors.len(), 80);
+        assert!(!neighbors.contains(&point));
+        let mut unique = neighbors.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 80);
+
+        let orthogonal = point.orthogonal_neighbors();
+        assert_eq!(orthogonal.len(), 8);
+        assert!(orthogonal.iter().all(|n| n.manhattan_distance(&point) == 1));
+
+        assert_eq!(PointN([0, 0]).neighbors().len(), 8);
+        assert_eq!(PointN([0]).neighbors(), [PointN([-1]), PointN([1])]);
+    }
+
+    #[test]
+    fn parses_what_it_displays() {
+        let point = PointN([1, -2, 3, 40]);
+        assert_eq!("1,-2,3,40".parse(), Ok(point));
+        assert_eq!(point.to_string(), "(1, -2, 3, 40)");
+        assert_eq!(point.to_string().parse(), Ok(point));
+    }
+
+    #[test]
+    fn rejects_malformed_points() {
+        for s in ["1,2,3", "1,2,3,4,5", "1,x,3,4", ""] {
+            assert!(s.parse::<PointN<4>>().is_err(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let mut point = PointN([1, 2, 3, 4]);
+        assert_eq!(point + PointN([1; 4]), PointN([2, 3, 4, 5]));
+        assert_eq!(point - PointN([1; 4]), PointN([0, 1, 2, 3]));
+        assert_eq!(-point, PointN([-1, -2, -3, -4]));
+        point[3] = 0;
+        assert_eq!(point.manhattan_distance(&PointN::origin()), 6);
+        assert_eq!(PointN::from(Point3::new(1, 2, 3)), PointN([1, 2, 3]));
+        assert_eq!(PointN::from(Point::new(1, 2)), PointN([1, 2]));
+    }
+
+    #[test]
+    fn bounds() {
+        let size = [2, 3, 1, 4];
+        assert!(PointN([0, 0, 0, 0]).in_bounds(size));
+        assert!(PointN([1, 2, 0, 3]).in_bounds(size));
+        assert!(!PointN([1, 2, 1, 3]).in_bounds(size));
+        assert!(!PointN([0, -1, 0, 0]).in_bounds(size));
+
+        let (min, max) = (PointN([-1, 0]), PointN([1, 0]));
+        assert!(min.in_box(&min, &max) && max.in_box(&min, &max));
+        assert!(!PointN([2, 0]).in_box(&min, &max));
+        assert!(!PointN([0, 1]).in_box(&min, &max));
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Bound, Range, Sub},
+};
+
+/// A set of values stored as sorted, disjoint half-open ranges.
+///
+/// Overlapping and touching ranges are merged on insertion, so every span returned by `iter` is
+/// separated from the next one by at least one uncovered value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    // Maps the start of every span to its end
+    spans: BTreeMap<T, T>,
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self {
+            spans: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The number of disjoint spans, not the number of covered values
+    pub fn span_count(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Iterates over the covered spans in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.spans.iter().map(|(&start, &end)| start..end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.spans
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| end > value)
+    }
+
+    /// Returns true if every value of the range is covered
+    pub fn contains_range(&self, range: Range<T>) -> bool {
+        range.is_empty()
+            || self
+                .spans
+                .range(..=range.start)
+                .next_back()
+                .is_some_and(|(_, &end)| end >= range.end)
+    }
+
+    /// Adds the range, merging it with every span it overlaps or touches
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let mut merged = range;
+        let touching: Vec<T> = self
+            .spans
+            .range(..=merged.end)
+            .rev()
+            .take_while(|(_, &end)| end >= merged.start)
+            .map(|(&start, _)| start)
+            .collect();
+        for start in touching {
+            let end = self.spans.remove(&start).unwrap();
+            merged = merged.start.min(start)..merged.end.max(end);
+        }
+
+        self.spans.insert(merged.start, merged.end);
+    }
+
+    /// Removes the range, splitting any span that only partly overlaps it
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let overlapping: Vec<T> = self
+            .spans
+            .range(..range.end)
+            .rev()
+            .take_while(|(_, &end)| end > range.start)
+            .map(|(&start, _)| start)
+            .collect();
+        for start in overlapping {
+            let end = self.spans.remove(&start).unwrap();
+            if start < range.start {
+                self.spans.insert(start, range.start);
+            }
+            if end > range.end {
+                self.spans.insert(range.end, end);
+            }
+        }
+    }
+
+    /// Returns the values covered by either set
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// Returns the values covered by both sets
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intersection = RangeSet::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.start.max(y.start)..x.end.min(y.end);
+            intersection.insert(overlap);
+            // Whichever span ends first can't overlap anything else
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        intersection
+    }
+
+    /// Iterates over the uncovered spans inside `within` in ascending order
+    pub fn gaps(&self, within: Range<T>) -> impl Iterator<Item = Range<T>> + '_ {
+        // A span starting before `within` may still cover its start
+        let mut spans = self
+            .spans
+            .range(..=within.start)
+            .next_back()
+            .into_iter()
+            .chain(
+                self.spans
+                    .range((Bound::Excluded(within.start), Bound::Unbounded)),
+            )
+            .map(|(&start, &end)| start..end);
+
+        let mut cursor = Some(within.start);
+        std::iter::from_fn(move || loop {
+            let start = cursor.filter(|&start| start < within.end)?;
+            match spans.next().filter(|span| span.start < within.end) {
+                Some(span) => {
+                    cursor = Some(start.max(span.end));
+                    if start < span.start {
+                        return Some(start..span.start);
+                    }
+                }
+                None => {
+                    cursor = None;
+                    return Some(start..within.end);
+                }
+            }
+        })
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> RangeSet<T> {
+    /// Returns the start of the first uncovered span inside `within` at least `len` long, e.g.
+    /// `first_gap(n, 0..p)` finds the first free space for `n` values ending before `p`
+    pub fn first_gap(&self, len: T, within: Range<T>) -> Option<T> {
+        self.gaps(within)
+            .find(|gap| gap.end - gap.start >= len)
+            .map(|gap| gap.start)
+    }
+}
+
+impl<T: Copy + Ord> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(ranges: impl Iterator<Item = Range<i32>>) -> Vec<(i32, i32)> {
+        ranges.map(|range| (range.start, range.end)).collect()
+    }
+
+    fn spans(set: &RangeSet<i32>) -> Vec<(i32, i32)> {
+        pairs(set.iter())
+    }
+
+    #[test]
+    fn inserts_merge_overlapping_and_touching_ranges() {
+        let mut set = RangeSet::new();
+        set.insert(0..3);
+        set.insert(5..8);
+        assert_eq!(spans(&set), [(0, 3), (5, 8)]);
+
+        // Touching on either side merges, a gap of one value doesn't
+        set.insert(3..4);
+        set.insert(9..10);
+        assert_eq!(spans(&set), [(0, 4), (5, 8), (9, 10)]);
+
+        // Bridging several spans at once
+        set.insert(2..9);
+        assert_eq!(spans(&set), [(0, 10)]);
+        set.insert(4..6);
+        assert_eq!(spans(&set), [(0, 10)]);
+    }
+
+    #[test]
+    fn removes_split_spans() {
+        let mut set = RangeSet::new();
+        set.insert(0..10);
+        set.remove(3..5);
+        assert_eq!(spans(&set), [(0, 3), (5, 10)]);
+        set.remove(8..20);
+        set.remove(-5..1);
+        assert_eq!(spans(&set), [(1, 3), (5, 8)]);
+        set.remove(2..6);
+        assert_eq!(spans(&set), [(1, 2), (6, 8)]);
+        set.remove(0..10);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn membership() {
+        let set: RangeSet<i32> = [0..3, 5..8].into_iter().collect();
+        assert!(set.contains(0) && set.contains(2) && set.contains(5));
+        assert!(!set.contains(3) && !set.contains(-1) && !set.contains(8));
+        assert!(set.contains_range(5..8));
+        assert!(set.contains_range(4..4));
+        assert!(!set.contains_range(2..6));
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a: RangeSet<i32> = [0..4, 6..10].into_iter().collect();
+        let b: RangeSet<i32> = [2..7, 10..12].into_iter().collect();
+        assert_eq!(spans(&a.union(&b)), [(0, 12)]);
+        assert_eq!(spans(&a.intersection(&b)), [(2, 4), (6, 7)]);
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn gaps_at_the_edges() {
+        let set: RangeSet<i32> = [0..3, 5..8].into_iter().collect();
+        assert_eq!(pairs(set.gaps(0..10)), [(3, 5), (8, 10)]);
+        // `within` starting inside a span, or ending inside one
+        assert_eq!(pairs(set.gaps(1..6)), [(3, 5)]);
+        assert_eq!(pairs(set.gaps(-2..1)), [(-2, 0)]);
+        assert_eq!(set.gaps(5..8).count(), 0);
+        assert_eq!(set.gaps(4..4).count(), 0);
+
+        assert_eq!(set.first_gap(2, 0..10), Some(3));
+        assert_eq!(set.first_gap(3, 0..10), None);
+        assert_eq!(set.first_gap(3, 0..11), Some(8));
+    }
+
+    #[test]
+    fn empty_sets() {
+        let mut set = RangeSet::<i32>::new();
+        assert!(set.is_empty());
+        assert_eq!(set.span_count(), 0);
+        assert!(!set.contains(0));
+        assert_eq!(pairs(set.gaps(0..5)), [(0, 5)]);
+        assert_eq!(set.first_gap(5, 0..5), Some(0));
+
+        // Empty ranges change nothing
+        set.insert(3..3);
+        set.remove(3..3);
+        assert!(set.is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{direction::Direction, grid::Grid, point::Point};
+
+/// Which neighbors of a cell count as connected to it
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Up, right, down and left
+    Four,
+    /// The four basic directions plus diagonals
+    Eight,
+}
+
+impl Connectivity {
+    fn directions(&self) -> &'static [Direction] {
+        const EIGHT: [Direction; 8] = [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::UpRight,
+            Direction::DownRight,
+            Direction::DownLeft,
+            Direction::UpLeft,
+        ];
+
+        match self {
+            Connectivity::Four => &Direction::ALL_BASIC,
+            Connectivity::Eight => &EIGHT,
+        }
+    }
+}
+
+/// A connected group of cells and its geometry.
+///
+/// Perimeter and sides always describe the outline made of cell edges, also for 8-connected
+/// regions, and include the edges of any holes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    id: usize,
+    cells: Vec<Point>,
+    perimeter: usize,
+    sides: usize,
+    min: Point,
+    max: Point,
+    touches_border: bool,
+}
+
+impl Region {
+    /// The label of the region, also its index in `Regions`
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The cells of the region in the order they were discovered
+    pub fn cells(&self) -> &[Point] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges between the region and anything outside it
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// The number of straight sides of the outline, counted as the number of corners
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+
+    /// Returns the top-left and bottom-right corners of the bounding box (inclusive)
+    pub fn bounding_box(&self) -> (Point, Point) {
+        (self.min, self.max)
+    }
+
+    /// Returns true if any cell of the region lies on the edge of the grid
+    pub fn touches_border(&self) -> bool {
+        self.touches_border
+    }
+}
+
+/// The result of labelling a grid: every region plus the label of every cell
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Returns the label of the region containing the point, if any
+    pub fn label(&self, point: impl Into<Point>) -> Option<usize> {
+        self.labels.get(point).copied().flatten()
+    }
+
+    /// Returns the region containing the point, if any
+    pub fn region_at(&self, point: impl Into<Point>) -> Option<&Region> {
+        self.label(point).map(|id| &self.regions[id])
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Region> {
+        self.regions.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+}
+
+impl IntoIterator for Regions {
+    type Item = Region;
+    type IntoIter = std::vec::IntoIter<Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.regions.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = std::slice::Iter<'a, Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.regions.iter()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Labels every cell, neighboring cells share a region when `connected` returns true for them
+    pub fn regions(
+        &self,
+        connectivity: Connectivity,
+        connected: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        self.label(connectivity, |_| true, connected)
+    }
+
+    /// Labels the cells matching `include`, neighboring matching cells always share a region
+    pub fn regions_where(
+        &self,
+        connectivity: Connectivity,
+        include: impl Fn(&T) -> bool,
+    ) -> Regions {
+        self.label(connectivity, include, |_, _| true)
+    }
+
+    fn label(
+        &self,
+        connectivity: Connectivity,
+        include: impl Fn(&T) -> bool,
+        connected: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        let mut labels = self.map(|_| None);
+        let mut regions = Vec::new();
+        let mut stack = Vec::new();
+
+        for (start, cell) in self.cells() {
+            if labels[start].is_some() || !include(cell) {
+                continue;
+            }
+
+            let id = regions.len();
+            let mut cells = Vec::new();
+            labels[start] = Some(id);
+            stack.push(start);
+            while let Some(pos) = stack.pop() {
+                cells.push(pos);
+                let cell = &self[pos];
+                for &dir in connectivity.directions() {
+                    let next = pos + dir;
+                    let Some(next_cell) = self.get(next) else {
+                        continue;
+                    };
+                    if labels[next].is_none() && include(next_cell) && connected(cell, next_cell) {
+                        labels[next] = Some(id);
+                        stack.push(next);
+                    }
+                }
+            }
+
+            regions.push(Region::measure(id, cells, &labels));
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+impl Region {
+    /// Computes the geometry of a freshly labelled region in time linear to its area
+    fn measure(id: usize, cells: Vec<Point>, labels: &Grid<Option<usize>>) -> Self {
+        let inside = |pos: Point| labels.get(pos) == Some(&Some(id));
+
+        let mut perimeter = 0;
+        let mut sides = 0;
+        let mut min = cells[0];
+        let mut max = cells[0];
+        let mut touches_border = false;
+        for &pos in &cells {
+            min = Point::new(min.x.min(pos.x), min.y.min(pos.y));
+            max = Point::new(max.x.max(pos.x), max.y.max(pos.y));
+            touches_border |= pos.x == 0
+                || pos.y == 0
+                || pos.x == labels.width() - 1
+                || pos.y == labels.height() - 1;
+
+            let adjacent = Direction::ALL_BASIC.map(|dir| inside(pos + dir));
+            let diagonal = Direction::ALL_DIAGONAL.map(|dir| inside(pos + dir));
+            perimeter += adjacent.iter().filter(|&&a| !a).count();
+            for i in 0..4 {
+                let (a, b) = (adjacent[i], adjacent[(i + 1) % 4]);
+                // Convex corner: both neighbors outside, concave: both inside but the diagonal isn't
+                if (!a && !b) || (a && b && !diagonal[i]) {
+                    sides += 1;
+                }
+            }
+        }
+
+        Self {
+            id,
+            cells,
+            perimeter,
+            sides,
+            min,
+            max,
+            touches_border,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input, |c| c)
+    }
+
+    #[test]
+    fn holes_add_to_the_outline() {
+        let regions = grid("AAA\nABA\nAAA\n").regions(Connectivity::Four, |a, b| a == b);
+        assert_eq!(regions.len(), 2);
+
+        let ring = regions.region_at(Point::new(0, 0)).unwrap();
+        assert_eq!((ring.area(), ring.perimeter(), ring.sides()), (8, 16, 8));
+        assert_eq!(ring.bounding_box(), (Point::new(0, 0), Point::new(2, 2)));
+        assert!(ring.touches_border());
+
+        let hole = regions.region_at(Point::new(1, 1)).unwrap();
+        assert_eq!((hole.area(), hole.perimeter(), hole.sides()), (1, 4, 4));
+        assert_eq!(hole.bounding_box(), (Point::new(1, 1), Point::new(1, 1)));
+        assert!(!hole.touches_border());
+        assert_eq!(regions.get(hole.id()), Some(hole));
+    }
+
+    #[test]
+    fn concave_corners_count_as_sides() {
+        let regions =
+            grid("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n").regions(Connectivity::Four, |a, b| a == b);
+        let areas_and_sides: Vec<_> = regions.iter().map(|r| (r.area(), r.sides())).collect();
+        assert_eq!(areas_and_sides, [(17, 12), (4, 4), (4, 4)]);
+        let e = regions.region_at(Point::new(0, 0)).unwrap();
+        assert_eq!(e.perimeter(), 36);
+    }
+
+    #[test]
+    fn diagonals_only_connect_with_eight() {
+        let diagonal = grid("#..\n.#.\n..#\n");
+
+        let four = diagonal.regions_where(Connectivity::Four, |&c| c == '#');
+        assert_eq!(four.len(), 3);
+        assert!(four.iter().all(|r| r.area() == 1));
+        assert_eq!(
+            four.region_at(Point::new(1, 1)).unwrap().bounding_box().0,
+            Point::new(1, 1)
+        );
+        assert!(!four.region_at(Point::new(1, 1)).unwrap().touches_border());
+
+        let eight = diagonal.regions_where(Connectivity::Eight, |&c| c == '#');
+        assert_eq!(eight.len(), 1);
+        let line = eight.get(0).unwrap();
+        // The outline is still made of cell edges, the cells only touch at corners
+        assert_eq!((line.area(), line.perimeter(), line.sides()), (3, 12, 12));
+        assert_eq!(line.bounding_box(), (Point::new(0, 0), Point::new(2, 2)));
+        assert!(line.touches_border());
+    }
+
+    #[test]
+    fn excluded_cells_have_no_label() {
+        let regions = grid("#.\n.#\n").regions_where(Connectivity::Four, |&c| c == '#');
+        assert_eq!(regions.label(Point::new(0, 0)), Some(0));
+        assert_eq!(regions.label(Point::new(1, 1)), Some(1));
+        assert_eq!(regions.label(Point::new(1, 0)), None);
+        assert_eq!(regions.label(Point::new(5, 5)), None);
+        assert!(regions.region_at(Point::new(0, 1)).is_none());
+
+        let none = grid("..\n").regions_where(Connectivity::Eight, |&c| c == '#');
+        assert!(none.is_empty());
+    }
+}
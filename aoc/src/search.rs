@@ -0,0 +1,503 @@
+//! Generic graph searches over any hashable state, e.g. a `Point` or a `(Point, Direction)`.
+//!
+//! Every search takes a start state, a closure producing the neighbors of a state and a goal
+//! predicate. BFS and DFS neighbors are plain states, Dijkstra and A* neighbors are
+//! `(state, cost)` pairs. Paths are returned including both the start and the goal.
+
+use fxhash::FxHashMap as HashMap;
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A state discovered during a search together with how it was reached
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parents: Vec<usize>,
+}
+
+/// Keeps every discovered state once and hands out dense indices for them
+struct Nodes<S, C> {
+    nodes: Vec<Node<S, C>>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Nodes<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        let mut index = HashMap::default();
+        index.insert(start.clone(), 0);
+        Self {
+            nodes: vec![Node {
+                state: start,
+                cost,
+                parents: Vec::new(),
+            }],
+            index,
+        }
+    }
+
+    /// Returns the index of the state and whether it was newly inserted
+    fn insert(&mut self, state: S, cost: C, parent: usize) -> (usize, bool) {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push(Node {
+                    state: entry.key().clone(),
+                    cost,
+                    parents: vec![parent],
+                });
+                entry.insert(i);
+                (i, true)
+            }
+        }
+    }
+
+    /// Follows the first parent of every node back to the start
+    fn path(&self, mut node: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[node].state.clone()];
+        while let Some(&parent) = self.nodes[node].parents.first() {
+            path.push(self.nodes[parent].state.clone());
+            node = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Enumerates every path from the start to `node` through all recorded parents
+    fn all_paths(&self, node: usize, paths: &mut Vec<Vec<S>>) {
+        let mut stack = vec![(node, vec![self.nodes[node].state.clone()])];
+        while let Some((node, path)) = stack.pop() {
+            let parents = &self.nodes[node].parents;
+            if parents.is_empty() {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+
+            for &parent in parents {
+                let mut path = path.clone();
+                path.push(self.nodes[parent].state.clone());
+                stack.push((parent, path));
+            }
+        }
+    }
+}
+
+/// Heap entry ordered so that `BinaryHeap` pops the lowest priority first
+struct Queued<C> {
+    priority: C,
+    cost: C,
+    node: usize,
+}
+
+impl<C: Ord> Ord for Queued<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl<C: Ord> PartialOrd for Queued<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Queued<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Queued<C> {}
+
+/// Breadth-first search, returns the number of steps and the path to the closest goal
+pub fn bfs<S, FN, IN, FG>(start: S, mut neighbors: FN, mut goal: FG) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut nodes = Nodes::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(node) = queue.pop_front() {
+        if goal(&nodes.nodes[node].state) {
+            return Some((nodes.nodes[node].cost, nodes.path(node)));
+        }
+
+        let cost = nodes.nodes[node].cost + 1;
+        for next in neighbors(&nodes.nodes[node].state) {
+            if let (next, true) = nodes.insert(next, cost, node) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Breadth-first search returning every shortest path to every goal at the minimum distance
+pub fn bfs_all<S, FN, IN, FG>(start: S, mut neighbors: FN, goal: FG) -> Option<(usize, Vec<Vec<S>>)>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    dijkstra_all(
+        start,
+        |state| neighbors(state).into_iter().map(|next| (next, 1)),
+        goal,
+    )
+}
+
+/// Returns the BFS distance to every state reachable from `start`, including itself
+pub fn reachable<S, FN, IN>(start: S, mut neighbors: FN) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::default();
+    distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back((entry.key().clone(), distance + 1));
+                entry.insert(distance + 1);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Depth-first search, returns the first path found to a goal which is not necessarily the shortest
+pub fn dfs<S, FN, IN, FG>(start: S, mut neighbors: FN, mut goal: FG) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut nodes = Nodes::new(start, 0);
+    let mut stack = vec![0];
+    while let Some(node) = stack.pop() {
+        if goal(&nodes.nodes[node].state) {
+            return Some(nodes.path(node));
+        }
+
+        let depth = nodes.nodes[node].cost + 1;
+        let mut next_nodes: Vec<_> = neighbors(&nodes.nodes[node].state)
+            .into_iter()
+            .filter_map(|next| match nodes.insert(next, depth, node) {
+                (next, true) => Some(next),
+                _ => None,
+            })
+            .collect();
+        // Visit neighbors in the order they were yielded
+        next_nodes.reverse();
+        stack.extend(next_nodes);
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, returns the total cost and the path to the cheapest goal
+pub fn dijkstra<S, C, FN, IN, FG>(start: S, neighbors: FN, goal: FG) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, neighbors, |_| C::default(), goal)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a goal
+pub fn astar<S, C, FN, IN, FH, FG>(
+    start: S,
+    mut neighbors: FN,
+    mut heuristic: FH,
+    mut goal: FG,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut heap = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: 0,
+    }]);
+    let mut nodes = Nodes::new(start, C::default());
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        // A cheaper way here was found after this entry was queued
+        if cost > nodes.nodes[node].cost {
+            continue;
+        }
+
+        if goal(&nodes.nodes[node].state) {
+            return Some((cost, nodes.path(node)));
+        }
+
+        for (next, step) in neighbors(&nodes.nodes[node].state) {
+            let next_cost = cost + step;
+            let priority = next_cost + heuristic(&next);
+            let (next, inserted) = nodes.insert(next, next_cost, node);
+            if !inserted {
+                if next_cost >= nodes.nodes[next].cost {
+                    continue;
+                }
+                nodes.nodes[next].cost = next_cost;
+                nodes.nodes[next].parents = vec![node];
+            }
+
+            heap.push(Queued {
+                priority,
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm returning every cheapest path to every goal reachable at the minimum cost.
+/// Step costs must be positive, otherwise zero-cost cycles would yield infinitely many paths.
+pub fn dijkstra_all<S, C, FN, IN, FG>(
+    start: S,
+    mut neighbors: FN,
+    mut goal: FG,
+) -> Option<(C, Vec<Vec<S>>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut heap = BinaryHeap::from([Queued {
+        priority: C::default(),
+        cost: C::default(),
+        node: 0,
+    }]);
+    let mut nodes = Nodes::new(start, C::default());
+    let mut best = None;
+    let mut goals = Vec::new();
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        if cost > nodes.nodes[node].cost {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        if goal(&nodes.nodes[node].state) {
+            best = Some(cost);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, step) in neighbors(&nodes.nodes[node].state) {
+            let next_cost = cost + step;
+            let (next, inserted) = nodes.insert(next, next_cost, node);
+            if !inserted {
+                match next_cost.cmp(&nodes.nodes[next].cost) {
+                    Ordering::Greater => continue,
+                    Ordering::Equal => {
+                        // Equal costs add another way in but the node is already queued
+                        nodes.nodes[next].parents.push(node);
+                        continue;
+                    }
+                    Ordering::Less => {
+                        nodes.nodes[next].cost = next_cost;
+                        nodes.nodes[next].parents = vec![node];
+                    }
+                }
+            }
+
+            heap.push(Queued {
+                priority: next_cost,
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    let mut paths = Vec::new();
+    for goal in goals {
+        nodes.all_paths(goal, &mut paths);
+    }
+    best.map(|best| (best, paths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FxHashMap, Grid, Point};
+
+    /// A small maze where the only way from the top-left to the top-right goes around the wall
+    const MAZE: &str = "\
+..#..
+..#..
+.....
+";
+
+    fn open_neighbors(grid: &Grid<char>) -> impl FnMut(&Point) -> Vec<Point> + '_ {
+        |point| {
+            grid.neighbors(*point, false)
+                .filter(|&next| grid[next] == '.')
+                .collect()
+        }
+    }
+
+    fn is_walk(path: &[Point]) -> bool {
+        path.windows(2)
+            .all(|pair| pair[0].manhattan_distance(&pair[1]) == 1)
+    }
+
+    /// a -> b -> d and a -> c -> d both cost 2, the direct a -> d costs 3
+    fn diamond(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 1), ('d', 3)],
+            'b' | 'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_finds_the_shortest_path() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let goal = Point::new(4, 0);
+        let (steps, path) = bfs(Point::new(0, 0), open_neighbors(&grid), |&p| p == goal).unwrap();
+        assert_eq!(steps, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), (Point::new(0, 0), goal));
+        assert!(is_walk(&path));
+    }
+
+    #[test]
+    fn searches_starting_on_the_goal_stop_there() {
+        let start = Point::new(1, 1);
+        assert_eq!(
+            bfs(start, |_| Vec::new(), |&p| p == start),
+            Some((0, vec![start]))
+        );
+        assert_eq!(
+            dfs(start, |_| Vec::new(), |&p| p == start),
+            Some(vec![start])
+        );
+        assert_eq!(
+            dijkstra(start, |_| Vec::<(Point, u32)>::new(), |&p| p == start),
+            Some((0, vec![start]))
+        );
+    }
+
+    #[test]
+    fn unreachable_goals_are_none() {
+        let grid = Grid::parse("..#..\n..#..\n", |c| c);
+        let goal = Point::new(4, 0);
+        let start = Point::new(0, 0);
+        assert_eq!(bfs(start, open_neighbors(&grid), |&p| p == goal), None);
+        assert_eq!(bfs_all(start, open_neighbors(&grid), |&p| p == goal), None);
+        assert_eq!(dfs(start, open_neighbors(&grid), |&p| p == goal), None);
+        let weighted = |p: &Point| open_neighbors(&grid)(p).into_iter().map(|next| (next, 1));
+        assert_eq!(dijkstra(start, weighted, |&p| p == goal), None::<(u32, _)>);
+        assert_eq!(
+            astar(start, weighted, |_| 0, |&p| p == goal),
+            None::<(u32, _)>
+        );
+        assert_eq!(
+            dijkstra_all(start, weighted, |&p| p == goal),
+            None::<(u32, _)>
+        );
+    }
+
+    #[test]
+    fn dfs_finds_some_path() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let goal = Point::new(4, 0);
+        let path = dfs(Point::new(0, 0), open_neighbors(&grid), |&p| p == goal).unwrap();
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.len() >= 9);
+        assert!(is_walk(&path));
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_detours() {
+        assert_eq!(
+            dijkstra('a', diamond, |&n| n == 'd'),
+            Some((2, vec!['a', 'b', 'd']))
+        );
+        // Without the middle nodes the direct edge is all that's left
+        let direct = |n: &char| diamond(n).into_iter().filter(|&(next, _)| next == 'd');
+        assert_eq!(
+            dijkstra('a', direct, |&n| n == 'd'),
+            Some((3, vec!['a', 'd']))
+        );
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let (start, goal) = (Point::new(0, 0), Point::new(4, 0));
+        let weighted = |p: &Point| open_neighbors(&grid)(p).into_iter().map(|next| (next, 1));
+        let (cost, path) = astar(
+            start,
+            weighted,
+            |p| p.manhattan_distance(&goal),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 8);
+        assert!(is_walk(&path));
+        assert_eq!(dijkstra(start, weighted, |&p| p == goal).unwrap().0, cost);
+    }
+
+    #[test]
+    fn all_variants_keep_every_tied_path() {
+        let (cost, mut paths) = dijkstra_all('a', diamond, |&n| n == 'd').unwrap();
+        paths.sort();
+        assert_eq!(cost, 2);
+        assert_eq!(paths, [vec!['a', 'b', 'd'], vec!['a', 'c', 'd']]);
+
+        // Both corners of an open 2x2 grid lead to the opposite one in two steps
+        let grid = Grid::parse("..\n..\n", |c| c);
+        let (steps, paths) = bfs_all(Point::new(0, 0), open_neighbors(&grid), |&p| {
+            p == Point::new(1, 1)
+        })
+        .unwrap();
+        assert_eq!((steps, paths.len()), (2, 2));
+
+        // Goals tied at the minimum distance are all reported
+        let (steps, paths) = bfs_all(Point::new(0, 0), open_neighbors(&grid), |p| {
+            p.manhattan_distance(&Point::new(0, 0)) == 1
+        })
+        .unwrap();
+        assert_eq!((steps, paths.len()), (1, 2));
+    }
+
+    #[test]
+    fn reachable_maps_every_distance() {
+        let grid = Grid::parse("..#.\n...#\n", |c| c);
+        let distances: FxHashMap<Point, usize> = reachable(Point::new(0, 0), open_neighbors(&grid));
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&Point::new(0, 0)], 0);
+        assert_eq!(distances[&Point::new(2, 1)], 3);
+        assert!(!distances.contains_key(&Point::new(3, 0)));
+    }
+}
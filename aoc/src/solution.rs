@@ -0,0 +1,139 @@
+use std::{any::Any, fmt::Display};
+
+use crate::ParseError;
+
+/// A day's puzzle, the input is parsed once and shared by both parts
+pub trait Solution {
+    type Input;
+
+    /// Fails with where the input stopped making sense, e.g. on a truncated download
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// A `Solution` with its types erased, so the runner can keep every day in one list
+#[derive(Clone)]
+pub struct Day {
+    pub year: u32,
+    pub number: u8,
+    /// The input embedded in the day's crate
+    pub input: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    part1: fn(&dyn Any) -> String,
+    part2: fn(&dyn Any) -> String,
+    variants: Vec<Variant>,
+}
+
+/// Another implementation of one of the parts, solving it straight from the raw input so it can
+/// be benchmarked against the day's parse and part
+#[derive(Clone, Copy)]
+pub struct Variant {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&str) -> String,
+}
+
+impl Day {
+    pub fn new<S: Solution>(year: u32, number: u8, input: &'static str) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year,
+            number,
+            input,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            part1: |parsed| S::part1(downcast::<S>(parsed)).to_string(),
+            part2: |parsed| S::part2(downcast::<S>(parsed)).to_string(),
+            variants: Vec::new(),
+        }
+    }
+
+    pub fn with_variant(mut self, name: &'static str, part: u8, solve: fn(&str) -> String) -> Self {
+        self.variants.push(Variant { name, part, solve });
+        self
+    }
+
+    pub fn variants(&self) -> &[Variant] {
+        &self.variants
+    }
+
+    /// Parses the input, the result is only meant to be passed back to `part1` and `part2`
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Panics if `parsed` didn't come from this day's `parse`
+    pub fn part1(&self, parsed: &dyn Any) -> String {
+        (self.part1)(parsed)
+    }
+
+    /// Panics if `parsed` didn't come from this day's `parse`
+    pub fn part2(&self, parsed: &dyn Any) -> String {
+        (self.part2)(parsed)
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref()
+        .expect("input was parsed by a different day")
+}
+
+/// Expands puzzle examples into `#[test]`s, one module per example with a test per given part.
+/// Paths are relative to the day's crate, and answers are compared through `Display`.
+///
+/// ```ignore
+/// aoc::examples!(Day03 {
+///     example_1: "examples/example-1.txt", part1 = 161;
+///     example_2: "examples/example-2.txt", part2 = 48;
+/// });
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty {
+        $($name:ident: $path:literal $(, part1 = $part1:expr)? $(, part2 = $part2:expr)?;)*
+    }) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                mod $name {
+                    use super::*;
+
+                    fn input() -> <$solution as $crate::Solution>::Input {
+                        <$solution as $crate::Solution>::parse(include_str!(concat!(
+                            env!("CARGO_MANIFEST_DIR"),
+                            "/",
+                            $path
+                        )))
+                        .unwrap_or_else(|e| panic!("{}: {e}", $path))
+                    }
+
+                    $(
+                        #[test]
+                        fn part1() {
+                            let input = input();
+                            let answer = <$solution as $crate::Solution>::part1(&input);
+                            assert_eq!(answer.to_string(), $part1.to_string());
+                        }
+                    )?
+
+                    $(
+                        #[test]
+                        fn part2() {
+                            let input = input();
+                            let answer = <$solution as $crate::Solution>::part2(&input);
+                            assert_eq!(answer.to_string(), $part2.to_string());
+                        }
+                    )?
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,297 @@
+use fxhash::FxHashMap as HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets over the dense indices `0..len`, with path compression and union by rank
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton sets
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Adds a new singleton set and returns its index
+    pub fn add(&mut self) -> usize {
+        let i = self.parents.len();
+        self.parents.push(i);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.components += 1;
+        i
+    }
+
+    /// The number of elements across all sets
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of disjoint sets
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Returns the representative of the set containing `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Point everything on the way directly at the root
+        let mut node = x;
+        while self.parents[node] != root {
+            node = std::mem::replace(&mut self.parents[node], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`, returns false if they already were the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the set containing `x`
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// Returns every set as a list of its members, ordered by their smallest member
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = HashMap::default();
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let i = *index.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[i].push(x);
+        }
+        components
+    }
+}
+
+/// Disjoint sets over arbitrary hashable keys, e.g. `Point`s, keys are added on first use
+#[derive(Debug, Clone)]
+pub struct HashUnionFind<K> {
+    keys: Vec<K>,
+    index: HashMap<K, usize>,
+    sets: UnionFind,
+}
+
+impl<K: Clone + Eq + Hash> Default for HashUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash> HashUnionFind<K> {
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            index: HashMap::default(),
+            sets: UnionFind::new(0),
+        }
+    }
+
+    /// Adds `key` as a singleton set, returns false if it was already present
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.index.contains_key(&key) {
+            return false;
+        }
+        self.index_of(key);
+        true
+    }
+
+    fn index_of(&mut self, key: K) -> usize {
+        if let Some(&i) = self.index.get(&key) {
+            return i;
+        }
+
+        let i = self.sets.add();
+        self.keys.push(key.clone());
+        self.index.insert(key, i);
+        i
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// The number of keys across all sets
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The number of disjoint sets
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Returns the representative key of the set containing `key`, if it has been added
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let i = *self.index.get(key)?;
+        let root = self.sets.find(i);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets containing `a` and `b`, adding missing keys first. Returns false if they
+    /// already were the same set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.index_of(a), self.index_of(b));
+        self.sets.union(a, b)
+    }
+
+    /// Returns true if both keys have been added and are in the same set
+    pub fn same_set(&mut self, a: &K, b: &K) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.same_set(a, b),
+            _ => false,
+        }
+    }
+
+    /// The number of keys in the set containing `key`, zero if it hasn't been added
+    pub fn size(&mut self, key: &K) -> usize {
+        match self.index.get(key) {
+            Some(&i) => self.sets.size(i),
+            None => 0,
+        }
+    }
+
+    /// Returns every set as a list of its keys, ordered by insertion
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|i| self.keys[i].clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_merge_sets_once() {
+        let mut sets = UnionFind::new(5);
+        assert_eq!((sets.len(), sets.component_count()), (5, 5));
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(sets.union(1, 4));
+        // Already joined, directly or through others
+        assert!(!sets.union(1, 0));
+        assert!(!sets.union(0, 3));
+
+        assert_eq!(sets.component_count(), 2);
+        assert!(sets.same_set(0, 4));
+        assert!(!sets.same_set(2, 4));
+        assert_eq!((sets.size(3), sets.size(2)), (4, 1));
+        assert_eq!(sets.components(), [vec![0, 1, 3, 4], vec![2]]);
+    }
+
+    #[test]
+    fn union_by_rank_keeps_the_taller_root() {
+        let mut sets = UnionFind::new(5);
+        sets.union(0, 1);
+        let root = sets.find(0);
+        // A singleton hangs below the rank 1 root, whichever side it's passed on
+        sets.union(2, 0);
+        assert_eq!(sets.find(2), root);
+        assert_eq!(sets.ranks[root], 1);
+
+        // Equal ranks grow the root by one
+        sets.union(3, 4);
+        sets.union(3, 0);
+        let root = sets.find(4);
+        assert_eq!(sets.ranks[root], 2);
+        assert_eq!(sets.size(root), 5);
+    }
+
+    #[test]
+    fn find_compresses_paths() {
+        let mut sets = UnionFind::new(4);
+        // Build the chain 3 -> 2 -> 1 -> 0 by hand, union by rank would never make one
+        sets.parents = vec![0, 0, 1, 2];
+        assert_eq!(sets.find(3), 0);
+        assert_eq!(sets.parents, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn added_elements_start_alone() {
+        let mut sets = UnionFind::default();
+        assert!(sets.is_empty());
+        assert_eq!(sets.add(), 0);
+        assert_eq!(sets.add(), 1);
+        assert_eq!(sets.component_count(), 2);
+        sets.union(0, 1);
+        assert_eq!(sets.add(), 2);
+        assert_eq!((sets.component_count(), sets.size(2)), (2, 1));
+    }
+
+    #[test]
+    fn hash_union_find_adds_keys_on_first_use() {
+        let mut sets = HashUnionFind::new();
+        assert!(sets.insert("a"));
+        assert!(!sets.insert("a"));
+        assert!(sets.union("b", "c"));
+        assert!(sets.union("c", "d"));
+        assert!(!sets.union("d", "b"));
+
+        assert_eq!((sets.len(), sets.component_count()), (4, 2));
+        assert!(sets.contains(&"d") && !sets.contains(&"e"));
+        assert!(sets.same_set(&"b", &"d"));
+        assert!(!sets.same_set(&"a", &"b"));
+        assert!(!sets.same_set(&"e", &"e"));
+        assert_eq!((sets.size(&"c"), sets.size(&"e")), (3, 0));
+        assert_eq!(sets.find(&"e"), None);
+        let root = *sets.find(&"b").unwrap();
+        assert_eq!(sets.find(&"d"), Some(&root));
+        assert_eq!(sets.components(), [vec!["a"], vec!["b", "c", "d"]]);
+    }
+}
@@ -0,0 +1,92 @@
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf};
+
+use anyhow::{bail, Context};
+
+/// Accepted answers, keyed by year, day, input hash and part so everyone's inputs can share one
+/// file
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<(u32, u8, u64, u8), String>,
+}
+
+impl Answers {
+    /// The answers file checked in at the root of the workspace
+    pub fn path() -> PathBuf {
+        crate::workspace_root().join("answers.txt")
+    }
+
+    /// Loads the answers file, a missing file has no answers yet
+    pub fn load(path: PathBuf) -> anyhow::Result<Self> {
+        let mut entries = BTreeMap::new();
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // The answer is last so it may contain spaces
+            let fields: Vec<&str> = line.splitn(5, ' ').collect();
+            let [year, day, hash, part, answer] = fields[..] else {
+                bail!(
+                    "{}:{}: expected `year day hash part answer`",
+                    path.display(),
+                    i + 1
+                );
+            };
+            let key = (
+                year.parse()?,
+                day.parse()?,
+                u64::from_str_radix(hash, 16)?,
+                part.parse()?,
+            );
+            entries.insert(key, answer.to_string());
+        }
+
+        Ok(Self { path, entries })
+    }
+
+    pub fn get(&self, year: u32, day: u8, input_hash: u64, part: u8) -> Option<&str> {
+        self.entries
+            .get(&(year, day, input_hash, part))
+            .map(String::as_str)
+    }
+
+    /// Returns the answer that was replaced, if any
+    pub fn record(
+        &mut self,
+        year: u32,
+        day: u8,
+        input_hash: u64,
+        part: u8,
+        answer: &str,
+    ) -> Option<String> {
+        self.entries
+            .insert((year, day, input_hash, part), answer.to_string())
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut content = String::from("# year day input-hash part answer\n");
+        for ((year, day, hash, part), answer) in &self.entries {
+            writeln!(content, "{year} {day:02} {hash:016x} {part} {answer}")?;
+        }
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+/// FNV-1a of the input, ignoring trailing whitespace and carriage returns so a re-saved file
+/// keeps its answers
+pub fn input_hash(input: &str) -> u64 {
+    input
+        .trim_end()
+        .bytes()
+        .filter(|&b| b != b'\r')
+        .fold(0xcbf29ce484222325, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        })
+}
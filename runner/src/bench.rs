@@ -0,0 +1,126 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc::{Day, ParseError};
+
+/// The timings of one step of a day over every iteration
+pub struct Measurement {
+    pub day: u8,
+    /// `parse`, `part1`, `part2`, `total` or a variant like `part2 (regex)`
+    pub step: String,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Measurement {
+    fn new(day: u8, step: String, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        // Nearest-rank percentile
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+        Self {
+            day,
+            step,
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// Times parsing and both parts separately, `total` is their sum within each iteration.
+/// Variants are timed from the raw input, so compare them with `parse` plus their part.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Vec<Measurement>, ParseError> {
+    let time = |f: &mut dyn FnMut()| {
+        let start = Instant::now();
+        f();
+        start.elapsed()
+    };
+
+    // Parsing the same input again can't fail differently
+    day.parse(input)?;
+    let parse = || day.parse(input).expect("the input parsed before");
+
+    for _ in 0..warmup {
+        let parsed = parse();
+        black_box(day.part1(parsed.as_ref()));
+        black_box(day.part2(parsed.as_ref()));
+    }
+
+    let mut samples: [Vec<Duration>; 4] = Default::default();
+    for _ in 0..iterations {
+        let mut parsed = None;
+        let parse = time(&mut || parsed = Some(parse()));
+        let parsed = parsed.unwrap();
+        let part1 = time(&mut || drop(black_box(day.part1(parsed.as_ref()))));
+        let part2 = time(&mut || drop(black_box(day.part2(parsed.as_ref()))));
+
+        let iteration = [parse, part1, part2, parse + part1 + part2];
+        for (samples, sample) in samples.iter_mut().zip(iteration) {
+            samples.push(sample);
+        }
+    }
+
+    let mut measurements: Vec<Measurement> = ["parse", "part1", "part2", "total"]
+        .into_iter()
+        .zip(samples)
+        .map(|(step, samples)| Measurement::new(day.number, step.to_string(), samples))
+        .collect();
+
+    for variant in day.variants() {
+        for _ in 0..warmup {
+            black_box((variant.solve)(input));
+        }
+        let samples = (0..iterations)
+            .map(|_| time(&mut || drop(black_box((variant.solve)(input)))))
+            .collect();
+        let step = format!("part{} ({})", variant.part, variant.name);
+        measurements.push(Measurement::new(day.number, step, samples));
+    }
+
+    Ok(measurements)
+}
+
+pub fn print_table(measurements: &[Measurement]) {
+    println!(
+        "Day  {:<16}  {:>10}  {:>10}  {:>10}",
+        "Step", "Min", "Median", "P95"
+    );
+    for m in measurements {
+        println!(
+            "{:>3}  {:<16}  {:>10}  {:>10}  {:>10}",
+            m.day,
+            m.step,
+            format!("{:.2?}", m.min),
+            format!("{:.2?}", m.median),
+            format!("{:.2?}", m.p95)
+        );
+    }
+}
+
+/// One JSON object per measurement with the times in nanoseconds
+pub fn print_json(measurements: &[Measurement], iterations: usize) {
+    let objects: Vec<String> = measurements
+        .iter()
+        .map(|m| {
+            format!(
+                r#"  {{"day": {}, "step": "{}", "iterations": {}, "min_ns": {}, "median_ns": {}, "p95_ns": {}}}"#,
+                m.day,
+                m.step,
+                iterations,
+                m.min.as_nanos(),
+                m.median.as_nanos(),
+                m.p95.as_nanos()
+            )
+        })
+        .collect();
+    println!("[\n{}\n]", objects.join(",\n"));
+}
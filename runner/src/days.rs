@@ -0,0 +1,37 @@
+use aoc::Day;
+
+/// Every solved day, in order of year and day
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_2024_01::Day01>(2024, 1, day_2024_01::INPUT),
+        Day::new::<day_2024_02::Day02>(2024, 2, day_2024_02::INPUT),
+        Day::new::<day_2024_03::Day03>(2024, 3, day_2024_03::INPUT).with_variant(
+            "regex",
+            2,
+            |input| day_2024_03::regex_solution(input).to_string(),
+        ),
+        Day::new::<day_2024_04::Day04>(2024, 4, day_2024_04::INPUT),
+        Day::new::<day_2024_05::Day05>(2024, 5, day_2024_05::INPUT),
+        Day::new::<day_2024_06::Day06>(2024, 6, day_2024_06::INPUT),
+        Day::new::<day_2024_07::Day07>(2024, 7, day_2024_07::INPUT),
+        Day::new::<day_2024_08::Day08>(2024, 8, day_2024_08::INPUT),
+        Day::new::<day_2024_09::Day09>(2024, 9, day_2024_09::INPUT),
+        Day::new::<day_2024_10::Day10>(2024, 10, day_2024_10::INPUT),
+        Day::new::<day_2024_11::Day11>(2024, 11, day_2024_11::INPUT),
+        Day::new::<day_2024_12::Day12>(2024, 12, day_2024_12::INPUT),
+        Day::new::<day_2024_13::Day13>(2024, 13, day_2024_13::INPUT),
+        Day::new::<day_2024_14::Day14>(2024, 14, day_2024_14::INPUT),
+        Day::new::<day_2024_15::Day15>(2024, 15, day_2024_15::INPUT),
+    ]
+}
+
+/// The solved days of one year
+pub fn year(year: u32) -> Vec<Day> {
+    all().into_iter().filter(|day| day.year == year).collect()
+}
+
+pub fn get(year: u32, number: u8) -> Option<Day> {
+    all()
+        .into_iter()
+        .find(|day| (day.year, day.number) == (year, number))
+}
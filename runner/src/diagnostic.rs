@@ -0,0 +1,28 @@
+use std::fmt::Write;
+
+use aoc::ParseError;
+
+/// Shows a parse error on the line it's about, with carets under the offending part:
+///
+/// ```text
+/// expected `|`, found nothing
+///  --> 2024/day-05/input1.txt:3:6
+///   |
+/// 3 | 47 53
+///   |      ^ expected `|`
+/// ```
+pub fn render(error: &ParseError, input: &str, source: &str) -> String {
+    let line = input.lines().nth(error.line - 1).unwrap_or_default();
+    let number = error.line.to_string();
+    let gutter = " ".repeat(number.len());
+    let indent = " ".repeat(error.column - 1);
+    let carets = "^".repeat(error.snippet.chars().count().max(1));
+
+    let mut diagnostic = error.summary();
+    let _ = write!(
+        diagnostic,
+        "\n{gutter}--> {source}:{}:{}\n{gutter} |\n{number} | {line}\n{gutter} | {indent}{carets} expected {}",
+        error.line, error.column, error.expected
+    );
+    diagnostic
+}
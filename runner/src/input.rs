@@ -0,0 +1,69 @@
+use std::{borrow::Cow, io::Read, path::PathBuf};
+
+use anyhow::Context;
+use aoc::Day;
+
+/// Where a day's puzzle input is read from
+pub enum Input {
+    /// The input compiled into the day's crate
+    Embedded,
+    /// A file, or stdin for `-`
+    Path(PathBuf),
+    /// `YEAR/day-XX/examples/example-N.txt`
+    Example(u32),
+}
+
+impl Input {
+    pub fn new(path: Option<PathBuf>, example: Option<u32>) -> Self {
+        match (path, example) {
+            (Some(path), _) => Input::Path(path),
+            (None, Some(n)) => Input::Example(n),
+            (None, None) => Input::Embedded,
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> anyhow::Result<Cow<'static, str>> {
+        let path = match self {
+            Input::Embedded => return Ok(Cow::Borrowed(day.input)),
+            Input::Path(path) if path.as_os_str() == "-" => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("failed to read input from stdin")?;
+                return Ok(Cow::Owned(input));
+            }
+            Input::Path(path) => path.clone(),
+            Input::Example(n) => day_dir(day)
+                .join("examples")
+                .join(format!("example-{n}.txt")),
+        };
+
+        std::fs::read_to_string(&path)
+            .map(Cow::Owned)
+            .with_context(|| format!("failed to read input from {}", path.display()))
+    }
+
+    /// Where the input comes from, for pointing at it in diagnostics
+    pub fn source(&self, day: &Day) -> String {
+        let path = match self {
+            Input::Embedded => day_dir(day).join("input1.txt"),
+            Input::Path(path) if path.as_os_str() == "-" => return "<stdin>".to_string(),
+            Input::Path(path) => return path.display().to_string(),
+            Input::Example(n) => day_dir(day)
+                .join("examples")
+                .join(format!("example-{n}.txt")),
+        };
+        let root = crate::workspace_root();
+        path.strip_prefix(&root)
+            .unwrap_or(&path)
+            .display()
+            .to_string()
+    }
+}
+
+/// The day's crate directory in the workspace
+pub fn day_dir(day: &Day) -> PathBuf {
+    crate::workspace_root()
+        .join(day.year.to_string())
+        .join(format!("day-{:02}", day.number))
+}
@@ -0,0 +1,366 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use answers::Answers;
+use anyhow::{anyhow, bail, Context};
+use aoc::{
+    check::{self, Check},
+    Day,
+};
+use clap::{Parser, Subcommand};
+use input::Input;
+use submit::{Rejected, Submitter, Verdict};
+
+mod answers;
+mod bench;
+mod days;
+mod diagnostic;
+mod input;
+mod scaffold;
+mod submit;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// The puzzle year, `AOC_YEAR` from aoc.env by default
+    #[arg(long, global = true, env = "AOC_YEAR")]
+    year: Option<u32>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with `--all`
+    Run {
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[arg(long)]
+        all: bool,
+        /// Read the input from this file instead of the embedded one, `-` reads stdin
+        #[arg(conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Use `YEAR/day-XX/examples/example-N.txt` as the input
+        #[arg(long, value_name = "N", conflicts_with_all = ["all", "input"])]
+        example: Option<u32>,
+    },
+    /// Check every day, or only `DAY`, against the answers recorded for its embedded input
+    Verify {
+        day: Option<u8>,
+        /// Record the current answers instead, e.g. after they were accepted
+        #[arg(long)]
+        record: bool,
+    },
+    /// Look for problems with the inputs of every day, or only `DAY`: a byte order mark, `\r\n`
+    /// line endings, trailing whitespace, parse errors, or an input2.txt that isn't input1.txt
+    Check { day: Option<u8> },
+    /// Time parsing and both parts of one day, or every day with `--all`
+    Bench {
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        #[arg(long)]
+        all: bool,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Print the measurements as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a new day from the templates and register it here
+    New { day: u8 },
+    /// Submit an answer, solving the part on its embedded input unless one is given
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+        /// Where to submit to, e.g. a local stub server
+        #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+        base_url: String,
+    },
+}
+
+/// One line of the answer table
+struct Row {
+    day: u8,
+    part: u8,
+    answer: String,
+    time: Duration,
+}
+
+/// The root of the workspace, where the years of days and the answers file live
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn main() -> anyhow::Result<()> {
+    // Neither overrides variables that are already set, so .env takes precedence over aoc.env
+    dotenvy::dotenv().ok();
+    dotenvy::from_path(workspace_root().join("aoc.env")).ok();
+
+    let cli = Cli::parse();
+    let year = cli
+        .year
+        .context("no year given, pass --year or set AOC_YEAR in aoc.env")?;
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            input,
+            example,
+        } => {
+            let input = Input::new(input, example);
+            let days = select_days(year, day.filter(|_| !all))?;
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let mut rows = Vec::new();
+            for day in &days {
+                let source = input.source(day);
+                let input = input.read(day)?;
+                rows.extend(solve(day, &input, &source, &parts)?);
+            }
+            print_table(&rows);
+        }
+        Command::Verify { day, record } => verify(&select_days(year, day)?, record)?,
+        Command::Check { day } => check(&select_days(year, day)?)?,
+        Command::Bench {
+            day,
+            all,
+            warmup,
+            iterations,
+            json,
+        } => {
+            let mut measurements = Vec::new();
+            for day in select_days(year, day.filter(|_| !all))? {
+                let bench =
+                    bench::bench(&day, day.input, warmup, iterations as usize).map_err(|e| {
+                        let source = Input::Embedded.source(&day);
+                        anyhow!(diagnostic::render(&e, day.input, &source))
+                    })?;
+                measurements.extend(bench);
+            }
+            if json {
+                bench::print_json(&measurements, iterations as usize);
+            } else {
+                bench::print_table(&measurements);
+            }
+        }
+        Command::New { day } => scaffold::new_day(year, day)?,
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        } => {
+            let day = &select_days(year, Some(day))?[0];
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    solve(day, day.input, &Input::Embedded.source(day), &[part])?
+                        .remove(0)
+                        .answer
+                }
+            };
+            submit(day, part, &answer, &base_url)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits an answer for the day's embedded input, refusing ones that are already known to be
+/// wrong. Accepted answers are recorded for `verify`.
+fn submit(day: &Day, part: u8, answer: &str, base_url: &str) -> anyhow::Result<()> {
+    let hash = answers::input_hash(day.input);
+    let mut answers = Answers::load(Answers::path())?;
+    match answers.get(day.year, day.number, hash, part) {
+        Some(accepted) if accepted == answer => {
+            println!("{answer} was already accepted");
+            return Ok(());
+        }
+        Some(accepted) => bail!("part {part} was already solved with {accepted}"),
+        None => {}
+    }
+
+    let mut rejected = Rejected::load(Rejected::path())?;
+    if let Some(reason) = rejected.reason(day.year, day.number, hash, part, answer) {
+        bail!("not submitting {answer}, {reason}");
+    }
+
+    let session = std::env::var("SESSION").context("should have a session token set")?;
+    println!(
+        "Submitting {answer} for {} day {} part {part}",
+        day.year, day.number
+    );
+    let verdict = Submitter::new(base_url, day.year, session).submit(day.number, part, answer)?;
+    match verdict {
+        Verdict::Correct => {
+            answers.record(day.year, day.number, hash, part, answer);
+            answers.save()?;
+        }
+        Verdict::Wrong(hint) => {
+            rejected.record(day.year, day.number, hash, part, hint, answer);
+            rejected.save()?;
+        }
+        Verdict::TooSoon(_) | Verdict::WrongLevel => {}
+    }
+
+    if verdict != Verdict::Correct {
+        bail!("{verdict}");
+    }
+    println!("{verdict}");
+    Ok(())
+}
+
+/// Only the given day of the year, or every day of it
+fn select_days(year: u32, day: Option<u8>) -> anyhow::Result<Vec<Day>> {
+    let Some(number) = day else {
+        let days = days::year(year);
+        if days.is_empty() {
+            bail!("{year} has no solutions yet");
+        }
+        return Ok(days);
+    };
+
+    match days::get(year, number) {
+        Some(day) => Ok(vec![day]),
+        None => bail!("{year} day {number} has no solution yet"),
+    }
+}
+
+fn verify(days: &[Day], record: bool) -> anyhow::Result<()> {
+    let mut answers = Answers::load(Answers::path())?;
+    let mut mismatches = 0;
+
+    println!("Day  Part  Status    Answer");
+    for day in days {
+        let hash = answers::input_hash(day.input);
+        for row in solve(day, day.input, &Input::Embedded.source(day), &[1, 2])? {
+            let (status, note) = match answers.get(day.year, day.number, hash, row.part) {
+                _ if record => {
+                    answers.record(day.year, day.number, hash, row.part, &row.answer);
+                    ("recorded", String::new())
+                }
+                None => ("missing", String::new()),
+                Some(expected) if expected == row.answer => ("ok", String::new()),
+                Some(expected) => {
+                    mismatches += 1;
+                    ("FAIL", format!("  (expected {expected})"))
+                }
+            };
+            println!(
+                "{:>3}  {:>4}  {:<8}  {}{}",
+                row.day, row.part, status, row.answer, note
+            );
+        }
+    }
+
+    if record {
+        answers.save()?;
+    }
+    if mismatches > 0 {
+        bail!("{mismatches} of the recorded answers didn't match");
+    }
+    Ok(())
+}
+
+fn check(days: &[Day]) -> anyhow::Result<()> {
+    let mut problems = 0;
+    for day in days {
+        let source = Input::Embedded.source(day);
+        let mut found = Vec::new();
+        for issue in Check::lines().report(day.input) {
+            found.push(format!("{source}: {issue}"));
+        }
+        if let Err(e) = day.parse(day.input) {
+            found.push(diagnostic::render(&e, day.input, &source));
+        }
+        let copy = input::day_dir(day).join("input2.txt");
+        if copy.exists() {
+            let text = std::fs::read_to_string(&copy)
+                .with_context(|| format!("failed to read {}", copy.display()))?;
+            if let Some(line) = check::first_difference(day.input, &text) {
+                let copy = source.replace("input1.txt", "input2.txt");
+                found.push(format!("{copy} differs from {source} from line {line} on"));
+            }
+        }
+
+        if found.is_empty() {
+            println!("{} day {:02}: ok", day.year, day.number);
+        } else {
+            println!("{} day {:02}:", day.year, day.number);
+            for problem in &found {
+                println!("{problem}\n");
+            }
+        }
+        problems += found.len();
+    }
+
+    if problems > 0 {
+        let plural = if problems == 1 { "" } else { "s" };
+        bail!("found {problems} problem{plural} with the inputs");
+    }
+    Ok(())
+}
+
+/// Parses the day's input once and solves the requested parts, the parse time is added to the
+/// first part. `source` is where the input came from, for reporting parse errors.
+fn solve(day: &Day, input: &str, source: &str, parts: &[u8]) -> anyhow::Result<Vec<Row>> {
+    let start = Instant::now();
+    let parsed = day
+        .parse(input)
+        .map_err(|e| anyhow!(diagnostic::render(&e, input, source)))?;
+    let mut parse_time = Some(start.elapsed());
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => day.part1(parsed.as_ref()),
+                _ => day.part2(parsed.as_ref()),
+            };
+            Row {
+                day: day.number,
+                part,
+                answer,
+                time: start.elapsed() + parse_time.take().unwrap_or_default(),
+            }
+        })
+        .collect())
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .map(|row| row.answer.len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("Day  Part  {:<answer_width$}  {:>10}", "Answer", "Time");
+    for row in rows {
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}",
+            row.day,
+            row.part,
+            row.answer,
+            format!("{:.2?}", row.time)
+        );
+    }
+}
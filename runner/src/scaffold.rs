@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+const CARGO_TEMPLATE: &str = include_str!("../../templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../../templates/day/lib.rs.tmpl");
+
+/// Creates `YEAR/day-XX` from the templates and registers it in the runner. Files and
+/// registrations that already exist are left alone, so this can be rerun safely.
+pub fn new_day(year: u32, number: u8) -> anyhow::Result<()> {
+    let root = crate::workspace_root();
+    let name = format!("{number:02}");
+    let dir = root.join(year.to_string()).join(format!("day-{name}"));
+    let render = |template: &str| {
+        template
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &name)
+    };
+
+    create(&dir.join("Cargo.toml"), &render(CARGO_TEMPLATE))?;
+    create(&dir.join("src").join("lib.rs"), &render(LIB_TEMPLATE))?;
+    // Empty placeholders so the crate builds before the input and examples are fetched
+    create(&dir.join("input1.txt"), "")?;
+    create(&dir.join("examples").join("example-1.txt"), "")?;
+
+    register(
+        &root.join("runner").join("Cargo.toml"),
+        &format!("day-{year}-{name} = {{ path = \"../{year}/day-{name}\" }}\n"),
+        manifest_position,
+    )?;
+    register(
+        &root.join("runner").join("src").join("days.rs"),
+        &format!(
+            "        Day::new::<day_{year}_{name}::Day{name}>({year}, {number}, day_{year}_{name}::INPUT),\n"
+        ),
+        days_position,
+    )?;
+
+    Ok(())
+}
+
+/// Keeps the day dependencies sorted, they're the last dependencies of the runner
+fn manifest_position(manifest: &str, line: &str) -> Option<usize> {
+    sorted_position(manifest, "day-", line).or_else(|| {
+        let last = manifest.rfind("\nday-")? + 1;
+        Some(last + manifest[last..].find('\n')? + 1)
+    })
+}
+
+/// Keeps the list in `all` sorted, a day after every other one goes before its end
+fn days_position(days: &str, line: &str) -> Option<usize> {
+    sorted_position(days, "        Day::new::<", line).or_else(|| days.find("    ]\n"))
+}
+
+/// The start of the first line beginning with `prefix` that sorts after `line`. Both name the
+/// day first, with the year and a zero-padded day, so they sort by (year, day).
+fn sorted_position(content: &str, prefix: &str, line: &str) -> Option<usize> {
+    let mut start = 0;
+    for existing in content.split_inclusive('\n') {
+        if existing.starts_with(prefix) && existing > line {
+            return Some(start);
+        }
+        start += existing.len();
+    }
+    None
+}
+
+fn create(path: &Path, content: &str) -> anyhow::Result<()> {
+    if path.exists() {
+        println!("skipped {}, it already exists", path.display());
+        return Ok(());
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, content).with_context(|| format!("failed to write {}", path.display()))?;
+    println!("created {}", path.display());
+    Ok(())
+}
+
+/// Inserts `line` into the file at the position found by `at`, unless it's already there
+fn register(
+    path: &Path,
+    line: &str,
+    at: impl Fn(&str, &str) -> Option<usize>,
+) -> anyhow::Result<()> {
+    let mut content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    if content.contains(line) {
+        println!("skipped {}, the day is already registered", path.display());
+        return Ok(());
+    }
+
+    let Some(i) = at(&content, line) else {
+        bail!(
+            "couldn't find where to register the day in {}",
+            path.display()
+        );
+    };
+    content.insert_str(i, line);
+    std::fs::write(path, content).with_context(|| format!("failed to write {}", path.display()))?;
+    println!("registered the day in {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(content: &str, line: &str, at: fn(&str, &str) -> Option<usize>) -> String {
+        let mut content = content.to_string();
+        content.insert_str(at(&content, line).unwrap(), line);
+        content
+    }
+
+    #[test]
+    fn dependencies_stay_sorted() {
+        let manifest = "[dependencies]\naoc = { path = \"../aoc\" }\n\
+            day-2023-25 = { path = \"../2023/day-25\" }\n\
+            day-2024-05 = { path = \"../2024/day-05\" }\n\
+            day-2024-10 = { path = \"../2024/day-10\" }\n";
+        let line = "day-2024-06 = { path = \"../2024/day-06\" }\n";
+        assert_eq!(
+            insert(manifest, line, manifest_position),
+            manifest.replace("day-2024-10", &format!("{line}day-2024-10"))
+        );
+
+        let last = "day-2024-11 = { path = \"../2024/day-11\" }\n";
+        assert_eq!(
+            insert(manifest, last, manifest_position),
+            format!("{manifest}{last}")
+        );
+    }
+
+    #[test]
+    fn days_stay_sorted() {
+        let entry = |year, number| {
+            format!(
+                "        Day::new::<day_{year}_{number:02}::Day{number:02}>({year}, {number}, day_{year}_{number:02}::INPUT),\n"
+            )
+        };
+        let days = format!(
+            "pub fn all() -> Vec<Day> {{\n    vec![\n{}{}        .with_variant(),\n    ]\n}}\n",
+            entry(2024, 5),
+            entry(2024, 10),
+        );
+
+        let day = entry(2024, 6);
+        assert_eq!(
+            insert(&days, &day, days_position),
+            days.replace(&entry(2024, 10), &format!("{day}{}", entry(2024, 10)))
+        );
+        let earlier_year = entry(2023, 25);
+        assert_eq!(
+            insert(&days, &earlier_year, days_position),
+            days.replace(
+                &entry(2024, 5),
+                &format!("{earlier_year}{}", entry(2024, 5))
+            )
+        );
+        // After a day with a variant spanning several lines
+        assert_eq!(
+            insert(&days, &entry(2024, 11), days_position),
+            days.replace("    ]\n", &format!("{}    ]\n", entry(2024, 11)))
+        );
+    }
+}
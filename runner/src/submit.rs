@@ -0,0 +1,387 @@
+use std::{fmt, fmt::Write, path::PathBuf, time::Duration};
+
+use anyhow::{bail, Context};
+use regex::Regex;
+use reqwest::{blocking::Client, header::COOKIE};
+
+/// What the site said about a submitted answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Option<Hint>),
+    /// Submitted too soon after the previous answer, nothing was checked
+    TooSoon(Option<Duration>),
+    /// The part is already solved or still locked
+    WrongLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    pub fn parse(html: &str) -> anyhow::Result<Self> {
+        if html.contains("That's the right answer") {
+            return Ok(Verdict::Correct);
+        }
+        if html.contains("That's not the right answer") {
+            let hint = if html.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            return Ok(Verdict::Wrong(hint));
+        }
+        if html.contains("You gave an answer too recently") {
+            return Ok(Verdict::TooSoon(wait_time(html)));
+        }
+        if html.contains("You don't seem to be solving the right level") {
+            return Ok(Verdict::WrongLevel);
+        }
+        if html.contains("Please log in") {
+            bail!("the site asked to log in, SESSION has probably expired");
+        }
+        bail!("couldn't understand the response:\n{}", main_text(html))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::Wrong(None) => write!(f, "that's not the right answer"),
+            Verdict::Wrong(Some(Hint::TooHigh)) => write!(f, "that's not right, it's too high"),
+            Verdict::Wrong(Some(Hint::TooLow)) => write!(f, "that's not right, it's too low"),
+            Verdict::TooSoon(Some(wait)) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Verdict::TooSoon(None) => write!(f, "answered too recently, wait a bit"),
+            Verdict::WrongLevel => write!(f, "that part is already solved or still locked"),
+        }
+    }
+}
+
+/// Parses waits like "You have 34s left to wait" or "You have 4m 2s left to wait"
+fn wait_time(html: &str) -> Option<Duration> {
+    let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let captures = wait.captures(html)?;
+    let minutes: u64 = match captures.get(1) {
+        Some(minutes) => minutes.as_str().parse().ok()?,
+        None => 0,
+    };
+    let seconds: u64 = captures[2].parse().ok()?;
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+/// The text of the `<main>` element, for reporting responses that couldn't be parsed
+fn main_text(html: &str) -> String {
+    let main = Regex::new(r"(?s)<main>(.*)</main>").unwrap();
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let main = main
+        .captures(html)
+        .and_then(|c| c.get(1))
+        .map_or(html, |m| m.as_str());
+    tags.replace_all(main, "").trim().to_string()
+}
+
+/// Posts answers to the site, or to a stub server at another base URL
+pub struct Submitter {
+    http: Client,
+    base_url: String,
+    year: u32,
+    session: String,
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, year: u32, session: String) -> Self {
+        Self {
+            http: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            session,
+        }
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> anyhow::Result<Verdict> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let response = self
+            .http
+            .post(&url)
+            .header(COOKIE, format!("session={}", self.session))
+            .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+            .send()
+            .with_context(|| format!("failed to post to {url}"))?;
+
+        let status = response.status();
+        let body = response.text()?;
+        if !status.is_success() {
+            bail!("{url} responded with {status}: {}", main_text(&body));
+        }
+        Verdict::parse(&body)
+    }
+}
+
+/// Answers the site rejected, kept so they are never submitted again
+pub struct Rejected {
+    path: PathBuf,
+    entries: Vec<(u32, u8, u64, u8, Option<Hint>, String)>,
+}
+
+impl Rejected {
+    pub fn path() -> PathBuf {
+        crate::workspace_root()
+            .join(".cache")
+            .join("rejected-answers.txt")
+    }
+
+    /// Loads the rejected answers, a missing file has none yet
+    pub fn load(path: PathBuf) -> anyhow::Result<Self> {
+        let mut entries = Vec::new();
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(6, ' ').collect();
+            let [year, day, hash, part, hint, answer] = fields[..] else {
+                bail!(
+                    "{}:{}: expected `year day hash part hint answer`",
+                    path.display(),
+                    i + 1
+                );
+            };
+            let hint = match hint {
+                "high" => Some(Hint::TooHigh),
+                "low" => Some(Hint::TooLow),
+                _ => None,
+            };
+            entries.push((
+                year.parse()?,
+                day.parse()?,
+                u64::from_str_radix(hash, 16)?,
+                part.parse()?,
+                hint,
+                answer.to_string(),
+            ));
+        }
+
+        Ok(Self { path, entries })
+    }
+
+    /// Returns why `answer` is known to be wrong, numeric answers are also checked against the
+    /// too high and too low bounds
+    pub fn reason(
+        &self,
+        year: u32,
+        day: u8,
+        input_hash: u64,
+        part: u8,
+        answer: &str,
+    ) -> Option<String> {
+        let number: Option<i128> = answer.parse().ok();
+        self.entries
+            .iter()
+            .filter(|e| (e.0, e.1, e.2, e.3) == (year, day, input_hash, part))
+            .find_map(|(_, _, _, _, hint, rejected)| {
+                let bound: Option<i128> = rejected.parse().ok();
+                match (hint, number.zip(bound)) {
+                    _ if rejected == answer => Some(format!("{answer} was already rejected")),
+                    (Some(Hint::TooHigh), Some((n, bound))) if n >= bound => {
+                        Some(format!("{rejected} was already too high"))
+                    }
+                    (Some(Hint::TooLow), Some((n, bound))) if n <= bound => {
+                        Some(format!("{rejected} was already too low"))
+                    }
+                    _ => None,
+                }
+            })
+    }
+
+    pub fn record(
+        &mut self,
+        year: u32,
+        day: u8,
+        input_hash: u64,
+        part: u8,
+        hint: Option<Hint>,
+        answer: &str,
+    ) {
+        self.entries
+            .push((year, day, input_hash, part, hint, answer.to_string()));
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut content = String::from("# year day input-hash part hint answer\n");
+        for (year, day, hash, part, hint, answer) in &self.entries {
+            let hint = match hint {
+                Some(Hint::TooHigh) => "high",
+                Some(Hint::TooLow) => "low",
+                None => "-",
+            };
+            writeln!(
+                content,
+                "{year} {day:02} {hash:016x} {part} {hint} {answer}"
+            )?;
+        }
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    macro_rules! fixture {
+        ($name:literal) => {
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/fixtures/submit/",
+                $name
+            ))
+        };
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let verdict = |html| Verdict::parse(html).unwrap();
+        assert_eq!(verdict(fixture!("correct.html")), Verdict::Correct);
+        assert_eq!(verdict(fixture!("wrong.html")), Verdict::Wrong(None));
+        assert_eq!(
+            verdict(fixture!("too-high.html")),
+            Verdict::Wrong(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            verdict(fixture!("too-low.html")),
+            Verdict::Wrong(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            verdict(fixture!("too-soon.html")),
+            Verdict::TooSoon(Some(Duration::from_secs(242)))
+        );
+        assert_eq!(
+            verdict(fixture!("already-solved.html")),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn unknown_responses_show_the_main_text() {
+        let error = Verdict::parse(fixture!("unknown.html")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "couldn't understand the response:\nSomething unexpected happened on the way to the answer."
+        );
+        let error = Verdict::parse("<main>Please log in to continue</main>").unwrap_err();
+        assert!(error.to_string().contains("SESSION has probably expired"));
+    }
+
+    #[test]
+    fn reads_wait_times() {
+        let wait = |html| wait_time(html).map(|wait| wait.as_secs());
+        assert_eq!(wait("You have 34s left to wait."), Some(34));
+        assert_eq!(wait("You have 4m 2s left to wait."), Some(242));
+        assert_eq!(wait("You have to wait a bit."), None);
+    }
+
+    #[test]
+    fn rejected_answers_bound_later_ones() {
+        let mut rejected = Rejected {
+            path: PathBuf::new(),
+            entries: Vec::new(),
+        };
+        rejected.record(2024, 1, 0xabc, 1, Some(Hint::TooHigh), "500");
+        rejected.record(2024, 1, 0xabc, 1, Some(Hint::TooLow), "100");
+        rejected.record(2024, 1, 0xabc, 1, None, "abc");
+        let reason = |answer| rejected.reason(2024, 1, 0xabc, 1, answer);
+
+        assert_eq!(reason("500").as_deref(), Some("500 was already rejected"));
+        assert_eq!(reason("600").as_deref(), Some("500 was already too high"));
+        assert_eq!(reason("50").as_deref(), Some("100 was already too low"));
+        assert_eq!(reason("abc").as_deref(), Some("abc was already rejected"));
+        assert_eq!(reason("250"), None);
+        assert_eq!(reason("xyz"), None);
+        // Another part, day, year or input isn't affected
+        assert_eq!(rejected.reason(2024, 1, 0xabc, 2, "600"), None);
+        assert_eq!(rejected.reason(2024, 2, 0xabc, 1, "600"), None);
+        assert_eq!(rejected.reason(2023, 1, 0xabc, 1, "600"), None);
+        assert_eq!(rejected.reason(2024, 1, 0xdef, 1, "600"), None);
+    }
+
+    #[test]
+    fn rejected_answers_survive_a_save() {
+        let path = std::env::temp_dir().join(format!("aoc-rejected-{}.txt", std::process::id()));
+        let mut rejected = Rejected::load(path.clone()).unwrap();
+        rejected.record(2024, 7, 0x1f, 2, Some(Hint::TooLow), "41");
+        rejected.record(2024, 7, 0x1f, 2, None, "not a number");
+        rejected.save().unwrap();
+
+        let loaded = Rejected::load(path.clone()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.entries, rejected.entries);
+    }
+
+    #[test]
+    fn submits_to_a_stub_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = Vec::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                head.push(line.trim_end().to_string());
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let html = fixture!("too-low.html");
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{html}",
+                html.len()
+            )
+            .unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        let verdict = Submitter::new(&base_url, 2024, "token".to_string())
+            .submit(6, 2, "1748")
+            .unwrap();
+        assert_eq!(verdict, Verdict::Wrong(Some(Hint::TooLow)));
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!(head[0], "POST /2024/day/6/answer HTTP/1.1");
+        assert!(head.iter().any(|line| line == "cookie: session=token"));
+        assert_eq!(body, "level=2&answer=1748");
+    }
+}
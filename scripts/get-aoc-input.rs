@@ -3,46 +3,75 @@
 [package]
 edition = "2021"
 [dependencies]
-clap = { version = "4.2", features = ["derive"] }
+anyhow = "1.0.93"
+clap = { version = "4.2", features = ["derive", "env"] }
 nom = "7.1.3"
+regex = "1.11.1"
 reqwest = { version = "0.11.22", features=["blocking"] }
 dotenvy = "0.15.7"
 ---
 
+use anyhow::{bail, Context};
 use clap::{error::ErrorKind, CommandFactory, Parser};
 use nom::{
     bytes::complete::tag, character::complete,
     sequence::preceded, IResult,
 };
-use reqwest::{blocking::Client, header::COOKIE};
-use std::fs::File;
-use std::io::Write;
-use std::path::PathBuf;
+use regex::Regex;
+use reqwest::{blocking::Client, header::COOKIE, StatusCode};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 #[derive(Parser, Debug)]
 #[clap(version)]
 struct Args {
     /// day is expected to be formatted as
     /// `day-01` to match all other commands in
-    /// the repo
+    /// the repo, `day-1` is padded to that
     #[clap(short, long)]
     day: String,
+    /// defaults to `AOC_YEAR` from aoc.env, the days
+    /// live in `<year>/day-01`
+    #[clap(short, long, env = "AOC_YEAR")]
+    year: u32,
     /// a way to pass in the justfile directory
     /// so that we're always in the root without
     /// doing any shenanigans
     #[clap(long)]
     current_working_directory: PathBuf,
+    /// overwrite an existing input file
+    #[clap(long)]
+    force: bool,
+    /// download the input even if it's cached
+    #[clap(long)]
+    no_cache: bool,
+    /// fetch the puzzle page instead of the input,
+    /// saving every `<pre><code>` block to
+    /// `examples/example-N.txt`
+    #[clap(long)]
+    examples: bool,
+    /// where to fetch from, e.g. a local mock
+    /// server when testing this script
+    #[clap(
+        long,
+        env = "AOC_BASE_URL",
+        default_value = "https://adventofcode.com"
+    )]
+    base_url: String,
 }
 
 fn parse_day(input: &str) -> IResult<&str, u32> {
     preceded(tag("day-"), complete::u32)(input)
 }
 
-fn main() -> Result<(), reqwest::Error> {
+fn main() -> anyhow::Result<()> {
+    // neither overrides what's already set, so .env
+    // takes precedence over the defaults in aoc.env
     dotenvy::dotenv().ok();
-    let session = std::env::var("SESSION")
-        .expect("should have a session token set");
-    let args = Args::parse();
+    dotenvy::from_filename("aoc.env").ok();
+    let mut args = Args::parse();
     let Ok((_, day)) = parse_day(&args.day) else {
         let mut cmd = Args::command();
         cmd.error(
@@ -54,32 +83,424 @@ fn main() -> Result<(), reqwest::Error> {
         )
         .exit()
     };
+    // `just new 6` creates `day-06`, so `just input 6`
+    // has to write there too
+    args.day = format!("day-{day:02}");
+
+    if args.examples {
+        return examples(&args, day);
+    }
+
+    let session = std::env::var("SESSION").ok();
+    input(&args, day, session.as_deref())
+}
+
+/// Writes the day's input1.txt, from the cache unless
+/// `--no-cache` is set. Nothing is written when the
+/// download fails.
+fn input(
+    args: &Args,
+    day: u32,
+    session: Option<&str>,
+) -> anyhow::Result<()> {
+    // the days only embed input1.txt, an empty one is
+    // just the placeholder from `just new`
+    let file_path = day_dir(args).join("input1.txt");
+    let is_empty = std::fs::metadata(&file_path)
+        .is_ok_and(|metadata| metadata.len() == 0);
+    if file_path.exists() && !is_empty && !args.force {
+        bail!(
+            "{} already exists, pass --force to overwrite it",
+            file_path.display()
+        );
+    }
+
+    let cache_path = args
+        .current_working_directory
+        .join(".cache")
+        .join("inputs")
+        .join(args.year.to_string())
+        .join(format!("{}.txt", args.day));
+    let input_data = match std::fs::read_to_string(&cache_path) {
+        Ok(cached) if !args.no_cache => {
+            println!("using cached `{}`", cache_path.display());
+            cached
+        }
+        _ => {
+            let session =
+                session.context("should have a session token set")?;
+            let input_data =
+                fetch(args, &format!("day/{day}/input"), Some(session))?;
+            write(&cache_path, &input_data)?;
+            input_data
+        }
+    };
+
+    write(&file_path, &input_data)?;
+    println!("wrote {}", file_path.display());
+
+    Ok(())
+}
 
+fn day_dir(args: &Args) -> PathBuf {
+    args.current_working_directory
+        .join(args.year.to_string())
+        .join(&args.day)
+}
+
+/// `path` is relative to the year, e.g. `day/1/input`
+fn fetch(
+    args: &Args,
+    path: &str,
+    session: Option<&str>,
+) -> anyhow::Result<String> {
     let url = format!(
-        "https://adventofcode.com/2024/day/{day}/input"
+        "{}/{}/{path}",
+        args.base_url.trim_end_matches('/'),
+        args.year
     );
     println!("sending to `{}`", url);
 
-    let client = Client::new();
-    let input_data = client
-        .get(url)
-        .header(COOKIE, format!("session={session}"))
-        .send()?
-        .text()?;
-
-    for filename in ["input1.txt", "input2.txt"] {
-        let file_path = args
-            .current_working_directory
-            .join(&args.day)
-            .join(filename);
-        let mut file = File::create(&file_path)
-            .expect("should be able to create a file");
-
-        file.write_all(input_data.as_bytes()).expect(
-            "should be able to write to input file",
+    let mut request = Client::new().get(&url);
+    if let Some(session) = session {
+        request =
+            request.header(COOKIE, format!("session={session}"));
+    }
+    let response = request.send()?;
+    let status = response.status();
+    check_response(&url, status, response.text()?)
+}
+
+/// Returns the body, or why it isn't what was asked
+/// for. The site answers with an error page instead,
+/// sometimes without an error status.
+fn check_response(
+    url: &str,
+    status: StatusCode,
+    body: String,
+) -> anyhow::Result<String> {
+    if body.contains("Please log in") {
+        bail!(
+            "`{url}` asked to log in, SESSION has probably expired"
         );
-        println!("wrote {}", file_path.display());
+    }
+    if body.contains("Please don't repeatedly request") {
+        bail!(
+            "`{url}` isn't unlocked yet, try again once the puzzle is out"
+        );
+    }
+    if status == StatusCode::NOT_FOUND {
+        bail!("`{url}` doesn't exist, check the year and day");
+    }
+    if !status.is_success() {
+        bail!("`{url}` responded with {status}: {}", body.trim());
+    }
+
+    Ok(body)
+}
+
+fn write(path: &Path, data: &str) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, data).with_context(|| {
+        format!("should be able to write to {}", path.display())
+    })
+}
+
+/// Saves the examples of the puzzle page and prints
+/// the answers it emphasizes. Part two is only on the
+/// page once part one is solved, which needs SESSION.
+fn examples(args: &Args, day: u32) -> anyhow::Result<()> {
+    let session = std::env::var("SESSION").ok();
+    let html =
+        fetch(args, &format!("day/{day}"), session.as_deref())?;
+
+    let dir = day_dir(&args).join("examples");
+    let mut n = 0;
+    // the emphasized answers per example
+    let mut suggestions = BTreeMap::<usize, String>::new();
+    for (part, puzzle) in parse_puzzle(&html).iter().enumerate() {
+        let first = n + 1;
+        for example in &puzzle.examples {
+            n += 1;
+            let path = dir.join(format!("example-{n}.txt"));
+            if path.exists() && !args.force {
+                println!(
+                    "skipped {}, it already exists",
+                    path.display()
+                );
+                continue;
+            }
+            write(&path, example)?;
+            println!("wrote {}", path.display());
+        }
+
+        if let Some(value) = &puzzle.answer {
+            println!("part {} answer: {value}", part + 1);
+            // part two usually reuses the first example
+            let example = if first > n { 1 } else { first };
+            suggestions
+                .entry(example)
+                .or_default()
+                .push_str(&format!(", part{} = {value}", part + 1));
+        }
     }
 
+    if n == 0 {
+        bail!("no examples found on the puzzle page");
+    }
+
+    println!(
+        "\nsuggested tests, check the answers belong to these examples:"
+    );
+    println!("aoc::examples!(Day{:02} {{", day);
+    for (example, answers) in suggestions {
+        println!(
+            "    example_{example}: \
+             \"examples/example-{example}.txt\"{answers};"
+        );
+    }
+    println!("}});");
+
     Ok(())
 }
+
+/// The examples and answer shown for one part of a
+/// puzzle, decoded to plain text
+#[derive(Debug, PartialEq)]
+struct Part {
+    examples: Vec<String>,
+    answer: Option<String>,
+}
+
+/// Finds every `<pre><code>` block of each part, and its
+/// answer, the last emphasized code of the part
+fn parse_puzzle(html: &str) -> Vec<Part> {
+    let article = Regex::new(
+        r#"(?s)<article class="day-desc">(.*?)</article>"#,
+    )
+    .unwrap();
+    let example =
+        Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer =
+        Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+    article
+        .captures_iter(html)
+        .map(|article| {
+            let article = &article[1];
+            Part {
+                examples: example
+                    .captures_iter(article)
+                    .map(|block| decode(&block[1]))
+                    .collect(),
+                answer: answer
+                    .captures_iter(article)
+                    .last()
+                    .map(|value| decode(&value[1])),
+            }
+        })
+        .collect()
+}
+
+/// Strips tags like `<em>` and decodes the entities
+/// the puzzle pages use
+fn decode(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    const PAGE: &str = include_str!("fixtures/puzzle-page.html");
+
+    fn error(status: StatusCode, body: &str) -> String {
+        check_response("day/6", status, body.to_string())
+            .unwrap_err()
+            .to_string()
+    }
+
+    /// Answers one request per response, then returns the
+    /// request lines and cookies it got
+    fn serve(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, JoinHandle<Vec<(String, String)>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url =
+            format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    head.push(line.trim_end().to_string());
+                }
+                let cookie = head
+                    .iter()
+                    .find_map(|line| line.strip_prefix("cookie: "))
+                    .unwrap_or_default()
+                    .to_string();
+                requests.push((head[0].clone(), cookie));
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Stub\r\n\
+                     Content-Length: {}\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn reruns_keep_the_input_unless_forced() {
+        let root = std::env::temp_dir()
+            .join(format!("get-aoc-input-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let file = root.join("2024/day-06/input1.txt");
+        let cache = root.join(".cache/inputs/2024/day-06.txt");
+        let read = |path: &Path| std::fs::read_to_string(path).unwrap();
+        // any request to this fails, it's where no server runs
+        let offline = "http://127.0.0.1:1".to_string();
+        let args = |base_url: &str, force, no_cache| Args {
+            day: "day-06".to_string(),
+            year: 2024,
+            current_working_directory: root.clone(),
+            force,
+            no_cache,
+            examples: false,
+            base_url: base_url.to_string(),
+        };
+        let input = |args: &Args| input(args, 6, Some("token"));
+
+        // the placeholder from `just new` is replaced
+        write(&file, "").unwrap();
+        let (base_url, server) = serve(vec![(200, "1 2\n")]);
+        input(&args(&base_url, false, false)).unwrap();
+        assert_eq!(
+            server.join().unwrap(),
+            [(
+                "GET /2024/day/6/input HTTP/1.1".to_string(),
+                "session=token".to_string()
+            )]
+        );
+        assert_eq!(read(&file), "1 2\n");
+        assert_eq!(read(&cache), "1 2\n");
+
+        // a rerun doesn't touch it
+        write(&file, "edited\n").unwrap();
+        let error = input(&args(&offline, false, false)).unwrap_err();
+        assert!(error.to_string().contains("pass --force"));
+        assert_eq!(read(&file), "edited\n");
+
+        // --force rewrites it from the cache
+        input(&args(&offline, true, false)).unwrap();
+        assert_eq!(read(&file), "1 2\n");
+
+        // error pages aren't written anywhere
+        for (status, body) in [
+            (200, "Please log in to get your puzzle input."),
+            (404, "404 Not Found"),
+        ] {
+            write(&file, "edited\n").unwrap();
+            let (base_url, server) = serve(vec![(status, body)]);
+            assert!(input(&args(&base_url, true, true)).is_err());
+            server.join().unwrap();
+            assert_eq!(read(&file), "edited\n");
+            assert_eq!(read(&cache), "1 2\n");
+        }
+
+        // --no-cache downloads it again
+        let (base_url, server) = serve(vec![(200, "3 4\n")]);
+        input(&args(&base_url, true, true)).unwrap();
+        assert_eq!(server.join().unwrap().len(), 1);
+        assert_eq!(read(&file), "3 4\n");
+        assert_eq!(read(&cache), "3 4\n");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn passes_inputs_through() {
+        let body = "1 2\n".to_string();
+        let body = check_response("day/6/input", StatusCode::OK, body);
+        assert_eq!(body.unwrap(), "1 2\n");
+    }
+
+    #[test]
+    fn recognizes_error_pages() {
+        // the login page comes back with a success status
+        let login = "<p>Puzzle inputs differ by user.  Please log in \
+            to get your puzzle input.</p>";
+        assert!(error(StatusCode::OK, login).contains("SESSION"));
+        assert!(error(StatusCode::BAD_REQUEST, login).contains("SESSION"));
+
+        let locked = "Please don't repeatedly request this endpoint \
+            before it unlocks! The calendar countdown is synchronized \
+            with the server time; the link will be enabled on the \
+            calendar the instant this puzzle becomes available.";
+        assert!(error(StatusCode::NOT_FOUND, locked)
+            .contains("isn't unlocked yet"));
+
+        assert!(error(StatusCode::NOT_FOUND, "404 Not Found")
+            .contains("doesn't exist"));
+        assert_eq!(
+            error(StatusCode::INTERNAL_SERVER_ERROR, "oops\n"),
+            "`day/6` responded with 500 Internal Server Error: oops"
+        );
+    }
+
+    #[test]
+    fn finds_the_examples_and_answers_of_each_part() {
+        let parts = parse_puzzle(PAGE);
+        assert_eq!(
+            parts,
+            [
+                Part {
+                    examples: vec![
+                        "47|53\n97|13\n\n75,47,61,53,29\n".into(),
+                        "a < b && c > \"d\"\n".into(),
+                    ],
+                    answer: Some("143".into()),
+                },
+                Part {
+                    examples: vec![],
+                    answer: Some("123".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_tags_and_entities() {
+        assert_eq!(
+            decode("<em>x</em> &lt;&gt; &amp;lt; &#39;&quot;"),
+            "x <> &lt; '\""
+        );
+        assert_eq!(decode("plain"), "plain");
+    }
+}